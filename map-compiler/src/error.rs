@@ -0,0 +1,138 @@
+use std::fmt;
+
+/// Something wrong with the map, with as much of where it happened as is known so a designer can
+/// find it in LDtk
+#[derive(Debug)]
+pub struct CompileError {
+    pub kind: ErrorKind,
+    pub location: Box<Location>,
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Read(std::io::Error),
+    Parse(serde_json::Error),
+    Write(std::io::Error),
+    MissingTileset(&'static str),
+    MissingLayer(&'static str),
+    WrongTileset(&'static str),
+    MissingLevelField(&'static str),
+    UnknownCustomData(String),
+    UnknownEntity(String),
+    MissingEntityField(&'static str),
+    BadEntityField { field: &'static str, value: String },
+    OutsideLevel,
+    MissingBackground,
+    MissingSpawn,
+    ExtraSpawn,
+}
+
+/// Where an error is, every part is optional since not every error happens on a tile
+#[derive(Debug, Default, Clone)]
+pub struct Location {
+    pub level: Option<String>,
+    pub layer: Option<String>,
+    pub pixel: Option<(usize, usize)>,
+    pub grid: Option<(usize, usize)>,
+    pub tile_id: Option<usize>,
+}
+
+impl Location {
+    pub fn level(level: &str) -> Self {
+        Location {
+            level: Some(level.to_string()),
+            ..Location::default()
+        }
+    }
+
+    pub fn layer(self, layer: &str) -> Self {
+        Location {
+            layer: Some(layer.to_string()),
+            ..self
+        }
+    }
+
+    pub fn pixel(self, x: usize, y: usize) -> Self {
+        Location {
+            pixel: Some((x, y)),
+            ..self
+        }
+    }
+
+    pub fn grid(self, x: usize, y: usize) -> Self {
+        Location {
+            grid: Some((x, y)),
+            ..self
+        }
+    }
+
+    pub fn tile_id(self, tile_id: usize) -> Self {
+        Location {
+            tile_id: Some(tile_id),
+            ..self
+        }
+    }
+
+    pub fn error(self, kind: ErrorKind) -> CompileError {
+        CompileError {
+            kind,
+            location: Box::new(self),
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Read(error) => write!(f, "couldn't read the map: {error}"),
+            ErrorKind::Parse(error) => write!(f, "the map isn't valid LDtk JSON: {error}"),
+            ErrorKind::Write(error) => write!(f, "couldn't write the compiled levels: {error}"),
+            ErrorKind::MissingTileset(tileset) => write!(f, "no {tileset} tileset"),
+            ErrorKind::MissingLayer(layer) => write!(f, "no {layer} layer"),
+            ErrorKind::WrongTileset(tileset) => {
+                write!(f, "layer isn't drawn with the {tileset} tileset")
+            }
+            ErrorKind::MissingLevelField(field) => write!(f, "no {field} level field"),
+            ErrorKind::UnknownCustomData(data) => write!(f, "unknown custom data {data:?}"),
+            ErrorKind::UnknownEntity(entity) => write!(f, "unknown entity {entity}"),
+            ErrorKind::MissingEntityField(field) => write!(f, "no {field} field on the entity"),
+            ErrorKind::BadEntityField { field, value } => {
+                write!(f, "unknown {field} {value}")
+            }
+            ErrorKind::OutsideLevel => write!(f, "entity is outside the level"),
+            ErrorKind::MissingBackground => write!(f, "no wall or floor tile"),
+            ErrorKind::MissingSpawn => write!(f, "no spawn point"),
+            ErrorKind::ExtraSpawn => write!(f, "another spawn point, a level only has the one"),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            self.level.clone(),
+            self.layer.as_ref().map(|layer| format!("{layer} layer")),
+            self.pixel.map(|(x, y)| format!("pixel {x}, {y}")),
+            self.grid.map(|(x, y)| format!("tile {x}, {y}")),
+            self.tile_id.map(|tile_id| format!("tile id {tile_id}")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            parts.into_iter().flatten().collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = self.location.to_string();
+
+        if location.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{location}: {}", self.kind)
+        }
+    }
+}
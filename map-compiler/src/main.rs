@@ -1,8 +1,20 @@
-use itertools::Itertools;
-use std::{collections::HashSet, fs::File};
+mod error;
+mod solver;
 
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use error::{CompileError, ErrorKind, Location};
 use serde::Deserialize;
 use serde_json::{self};
+use solver::Solver;
+use the_cleaners::level::{self as game, BodyLevel, Character, Direction, Grid, LevelState};
+use the_cleaners::levels::{self, LevelDefinition};
 
 #[derive(Deserialize)]
 struct EnumTag {
@@ -21,6 +33,8 @@ struct CustomData {
 
 #[derive(Deserialize)]
 struct Tileset {
+    identifier: String,
+    uid: usize,
     #[serde(rename = "enumTags")]
     enum_tags: Vec<EnumTag>,
     #[serde(rename = "customData")]
@@ -32,6 +46,15 @@ struct Defs {
     tilesets: Vec<Tileset>,
 }
 
+impl Defs {
+    fn tileset(&self, identifier: &'static str) -> Result<&Tileset, CompileError> {
+        self.tilesets
+            .iter()
+            .find(|tileset| tileset.identifier == identifier)
+            .ok_or_else(|| Location::default().error(ErrorKind::MissingTileset(identifier)))
+    }
+}
+
 #[derive(Deserialize, Debug)]
 struct GridTile {
     #[serde(rename = "px")]
@@ -44,16 +67,111 @@ struct GridTile {
 
 #[derive(Deserialize)]
 struct LayerInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__tilesetDefUid")]
+    tileset_uid: Option<usize>,
     #[serde(rename = "gridTiles")]
     grid_tiles: Vec<GridTile>,
+    // Placed by the rules of an auto layer rather than by hand
+    #[serde(rename = "autoLayerTiles")]
+    auto_layer_tiles: Vec<GridTile>,
+    #[serde(rename = "entityInstances")]
+    entity_instances: Vec<EntityInstance>,
+}
+
+impl LayerInstance {
+    fn tiles(&self) -> impl Iterator<Item = &GridTile> {
+        self.grid_tiles.iter().chain(&self.auto_layer_tiles)
+    }
+}
+
+#[derive(Deserialize)]
+struct EntityInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__grid")]
+    grid: (usize, usize),
+    px: (usize, usize),
+    #[serde(rename = "fieldInstances")]
+    field_instances: Vec<FieldInstance>,
+}
+
+impl EntityInstance {
+    /// The raw value of one of the entity's fields, `None` when it isn't there or isn't set
+    fn field(&self, identifier: &str) -> Option<&serde_json::Value> {
+        self.field_instances
+            .iter()
+            .find(|field| field.identifier == identifier)
+            .map(|field| &field.value)
+            .filter(|value| !value.is_null())
+    }
+
+    /// The value of one of the entity's enum fields, `None` when it isn't set
+    fn enum_field(&self, identifier: &str) -> Option<&str> {
+        self.field(identifier).and_then(|value| value.as_str())
+    }
+}
+
+#[derive(Deserialize)]
+struct FieldInstance {
+    #[serde(rename = "__identifier")]
+    identifier: String,
+    #[serde(rename = "__value")]
+    value: serde_json::Value,
 }
 
 #[derive(Deserialize)]
 struct Level {
+    identifier: String,
+    #[serde(rename = "fieldInstances")]
+    field_instances: Vec<FieldInstance>,
     #[serde(rename = "layerInstances")]
     layer_instances: Vec<LayerInstance>,
 }
 
+impl Level {
+    /// The tile layers of the level, bottom first so each one gets drawn over the one before.
+    /// LDtk lists layers top first
+    fn tile_layers(&self, errors: &mut Vec<CompileError>) -> Vec<&LayerInstance> {
+        let layers = self
+            .layer_instances
+            .iter()
+            .filter(|layer| TILE_LAYERS.contains(&layer.identifier.as_str()))
+            .rev()
+            .collect::<Vec<_>>();
+
+        TILE_LAYERS.iter().for_each(|&identifier| {
+            if !layers.iter().any(|layer| layer.identifier == identifier) {
+                errors.push(
+                    Location::level(&self.identifier).error(ErrorKind::MissingLayer(identifier)),
+                );
+            }
+        });
+
+        layers
+    }
+
+    fn entity_layer(&self) -> Result<&LayerInstance, CompileError> {
+        self.layer_instances
+            .iter()
+            .find(|layer| layer.identifier == ENTITY_LAYER)
+            .ok_or_else(|| {
+                Location::level(&self.identifier).error(ErrorKind::MissingLayer(ENTITY_LAYER))
+            })
+    }
+
+    fn int_field(&self, identifier: &'static str) -> Result<u64, CompileError> {
+        self.field_instances
+            .iter()
+            .find(|field| field.identifier == identifier)
+            .and_then(|field| field.value.as_u64())
+            .ok_or_else(|| {
+                Location::level(&self.identifier).error(ErrorKind::MissingLevelField(identifier))
+            })
+    }
+}
+
 #[derive(Deserialize)]
 struct Ldtk {
     defs: Defs,
@@ -84,7 +202,7 @@ enum Item {
     BagRoll,
     BodyBag,
     Bag,
-    Body,
+    Body(BodyLevel),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -96,6 +214,12 @@ enum BloodLevel {
     Venti,
 }
 
+#[derive(Clone, Debug)]
+struct Spawn {
+    facing: Direction,
+    character: Option<Character>,
+}
+
 #[derive(Clone, Debug, Default)]
 struct Tile {
     background: TileBackground,
@@ -104,106 +228,383 @@ struct Tile {
     item: Item,
     blood_level: BloodLevel,
     drop_point: bool,
-    player: bool,
+    spawn: Option<Spawn>,
+    sink: bool,
+    doormat: bool,
 }
 
-fn level_function(tiles: &[Vec<Tile>], level: usize) -> String {
-    let mut character_position = None;
+/// Turns a compiled grid into the game's own level, ready to be stored in the level file
+fn level_definition(
+    tiles: &[Vec<Tile>],
+    level: &Level,
+    errors: &mut Vec<CompileError>,
+) -> LevelDefinition {
+    let name = &level.identifier;
+    let sprite = |Vec2 { x, y }: &Vec2| game::vec2(*x, *y);
+    let mut spawn = None;
 
-    let columns = tiles
+    let mut columns: Vec<Vec<game::Tile>> = tiles
         .iter()
         .enumerate()
         .map(|(x, column)| {
-            let column = column
+            column
                 .iter()
                 .enumerate()
                 .map(|(y, tile)| {
-                    let background = match tile.background {
-                        TileBackground::None => panic!("Invalid Tilebackground at {x}, {y}"),
-                        TileBackground::Wall(Vec2 { x, y }) => format!("Wall(vec2({x}, {y}))"),
-                        TileBackground::Floor(Vec2 { x, y }) => format!("Floor(vec2({x}, {y}))"),
+                    let background = match &tile.background {
+                        TileBackground::None => {
+                            errors.push(
+                                Location::level(name)
+                                    .pixel(x * 16, y * 16)
+                                    .grid(x, y)
+                                    .error(ErrorKind::MissingBackground),
+                            );
+                            game::TileBackground::Floor(game::vec2(0, 0))
+                        }
+                        TileBackground::Wall(at) => game::TileBackground::Wall(sprite(at)),
+                        TileBackground::Floor(at) => game::TileBackground::Floor(sprite(at)),
                     };
 
-                    let furniture = match tile.furniture {
-                        TileBackground::None => "None".to_string(),
-                        TileBackground::Wall(Vec2 { x, y }) => format!("Wall(vec2({x}, {y}))"),
-                        TileBackground::Floor(Vec2 { x, y }) => format!("Floor(vec2({x}, {y}))"),
-                    };
-
-                    let foreground = if let Some(foreground) = &tile.foreground {
-                        format!("Some(vec2({x}, {y}))", x = foreground.x, y = foreground.y)
-                    } else {
-                        "None".to_string()
+                    let furniture = match &tile.furniture {
+                        TileBackground::Wall(at) if tile.sink => game::Furniture::Sink(sprite(at)),
+                        TileBackground::Floor(at) if tile.doormat => {
+                            game::Furniture::Doormat(sprite(at))
+                        }
+                        TileBackground::None => game::Furniture::None,
+                        TileBackground::Wall(at) => game::Furniture::Wall(sprite(at)),
+                        TileBackground::Floor(at) => game::Furniture::Floor(sprite(at)),
                     };
 
                     let item = match tile.item {
-                        Item::None => "None".to_string(),
-                        Item::Bleach => "Bleach".to_string(),
-                        Item::Knife => "Knife".to_string(),
-                        Item::Sponge => "Sponge".to_string(),
-                        Item::BagRoll => "BagRoll".to_string(),
-                        Item::BodyBag => "BodyBag".to_string(),
-                        Item::Bag => "Bag".to_string(),
-                        Item::Body => "Body(BodyLevel::Start, BODY_CHOPPING_TIME)".to_string(),
+                        Item::None => game::Item::None,
+                        Item::Bleach => game::Item::Bleach(0),
+                        Item::Knife => game::Item::Knife,
+                        Item::Sponge => game::Item::Sponge(0),
+                        Item::BagRoll => game::Item::BagRoll,
+                        Item::BodyBag => game::Item::BodyBag,
+                        Item::Bag => game::Item::Bag,
+                        Item::Body(level) => game::Item::Body(level, game::BODY_CHOPPING_TIME),
                     };
 
                     let blood_level = match tile.blood_level {
-                        BloodLevel::None => "None".to_string(),
-                        BloodLevel::Tall => "Tall(CLEANING_TIME)".to_string(),
-                        BloodLevel::Grande => "Grande(CLEANING_TIME)".to_string(),
-                        BloodLevel::Venti => "Venti(CLEANING_TIME)".to_string(),
+                        BloodLevel::None => game::BloodLevel::None,
+                        BloodLevel::Tall => game::BloodLevel::Tall(game::CLEANING_TIME),
+                        BloodLevel::Grande => game::BloodLevel::Grande(game::CLEANING_TIME),
+                        BloodLevel::Venti => game::BloodLevel::Venti(game::CLEANING_TIME),
                     };
 
-                    let drop_point = tile.drop_point;
-                    let player = tile.player;
-
-                    if player {
-                        character_position = Some(format!("vec2({x}, {y})"));
+                    if let Some(Spawn { facing, character }) = tile.spawn {
+                        if spawn.is_some() {
+                            errors.push(
+                                Location::level(name)
+                                    .pixel(x * 16, y * 16)
+                                    .grid(x, y)
+                                    .error(ErrorKind::ExtraSpawn),
+                            );
+                        } else {
+                            spawn = Some(game::Spawn {
+                                position: game::vec2(x, y),
+                                facing,
+                                character,
+                            });
+                        }
                     }
 
-                    format!(
-                        "Tile {{
-                    background: TileBackground::{background},
-                    furniture: Furniture::{furniture},
-                    foreground: {foreground},
-                    item: Item::{item},
-                    player: {player},
-                    blood_level: BloodLevel::{blood_level},
-                    drop_point: {drop_point},
-                }}"
-                    )
+                    game::Tile {
+                        background,
+                        item,
+                        furniture,
+                        foreground: tile.foreground.as_ref().map(sprite),
+                        player: false,
+                        drop_point: tile.drop_point,
+                        blood_level,
+                        footprints: None,
+                    }
                 })
-                .join(",");
-
-            format!("vec![{column}]")
+                .collect()
         })
-        .join(",");
+        .collect();
 
-    let Some(character_position) = character_position else {
-        panic!("No SPAWN point");
+    let spawn = spawn.unwrap_or_else(|| {
+        errors.push(Location::level(name).error(ErrorKind::MissingSpawn));
+        game::Spawn {
+            position: game::vec2(0, 0),
+            facing: Direction::Down,
+            character: None,
+        }
+    });
+
+    // Only the spawn picked for the level has the cleaners standing on it
+    if let Some(tile) = columns
+        .get_mut(spawn.position.x)
+        .and_then(|column| column.get_mut(spawn.position.y))
+    {
+        tile.player = true;
+    }
+
+    let mut int_field = |identifier| {
+        level.int_field(identifier).unwrap_or_else(|error| {
+            errors.push(error);
+            0
+        }) as usize
     };
 
-    format!(
-        "
-pub fn create_level_{level}() -> (Vec<Vec<Tile>>, Vec2) {{
-    (vec![
-        {columns}
-    ], {character_position})
-}}
-"
-    )
+    LevelDefinition {
+        name: name.clone(),
+        par: game::Par {
+            three_stars: int_field("ThreeStarPar"),
+            two_stars: int_field("TwoStarPar"),
+        },
+        columns,
+        spawn,
+    }
 }
 
-fn tiles_file(functions: String) -> String {
-    format!("
-use crate::{{Tile, TileBackground, Item, vec2, BloodLevel, BodyLevel, BODY_CHOPPING_TIME, Furniture, CLEANING_TIME, Vec2}};
-        
-{functions}
-")
+/// The layers tiles are read from, any other layer in Cleaners.ldtk is left out of the game
+const TILE_LAYERS: [&str; 3] = ["Tiles", "Floor", "AutoLayer"];
+/// The layer gameplay objects are placed on, on top of anything the tiles say
+const ENTITY_LAYER: &str = "Entities";
+/// The tileset cut from the same sheet the game draws with
+const TILESET: &str = "The_Cleaners_Assets";
+
+/// How many states each search may come across before the solver gives up on it. Finding any
+/// solution to Level_1 takes about 600,000 since sponges have to be rinsed, and a million stays
+/// under half a gigabyte
+const DEFAULT_MAX_STATES: usize = 1_000_000;
+
+/// Where the map is read from and the levels are written to, wherever the compiler is run from
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cleaners.ldtk");
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/levels.bin");
+/// How often `--watch` looks at the map for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+const USAGE: &str = "Usage: map-compiler [INPUT] [--output PATH] [--check | --watch]
+       map-compiler solve [INPUT] [--max-states N]";
+
+#[derive(PartialEq, Debug)]
+enum Mode {
+    // Compile once and write the levels out
+    Write,
+    // Fail if the levels on disk don't match what the map compiles to
+    Check,
+    // Compile again every time the map is saved
+    Watch,
+}
+
+#[derive(PartialEq, Debug)]
+struct Options {
+    input: PathBuf,
+    output: PathBuf,
+    mode: Mode,
+}
+
+impl Options {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut args = args.peekable();
+        let mut options = Options {
+            input: PathBuf::from(DEFAULT_INPUT),
+            output: PathBuf::from(DEFAULT_OUTPUT),
+            mode: Mode::Write,
+        };
+        let mut input = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--output" | "-o" => {
+                    let path = args.next().ok_or("--output expects a path")?;
+                    options.output = PathBuf::from(path);
+                }
+                "--check" | "--watch" => {
+                    let mode = match arg.as_str() {
+                        "--check" => Mode::Check,
+                        _ => Mode::Watch,
+                    };
+
+                    // Saying the same thing twice is fine, asking for both isn't
+                    if options.mode != Mode::Write && options.mode != mode {
+                        return Err("--check and --watch can't be used together".to_string());
+                    }
+
+                    options.mode = mode;
+                }
+                flag if flag.starts_with('-') => return Err(format!("Unknown flag {flag}")),
+                path if input.is_none() => input = Some(PathBuf::from(path)),
+                path => return Err(format!("Expected a single map, got {path} as well")),
+            }
+        }
+
+        if let Some(input) = input {
+            options.input = input;
+        }
+
+        Ok(options)
+    }
 }
 
 fn main() {
-    let ldtk = serde_json::from_reader::<_, Ldtk>(File::open("./Cleaners.ldtk").unwrap()).unwrap();
+    let mut args = std::env::args().skip(1).peekable();
+
+    if args.peek().map(String::as_str) == Some("solve") {
+        args.next();
+
+        let mut input = None;
+        let mut max_states = DEFAULT_MAX_STATES;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--max-states" => {
+                    let value = args.next().unwrap_or_default();
+                    max_states = value.parse().unwrap_or_else(|_| {
+                        eprintln!("--max-states expects a number, got {value:?}");
+                        std::process::exit(2)
+                    });
+                }
+                path if !path.starts_with('-') && input.is_none() => {
+                    input = Some(PathBuf::from(path));
+                }
+                _ => {
+                    eprintln!("{USAGE}");
+                    std::process::exit(2)
+                }
+            }
+        }
+
+        let input = input.unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT));
+        return solve(&input, max_states);
+    }
+
+    let options = Options::parse(args).unwrap_or_else(|error| {
+        eprintln!("{error}\n{USAGE}");
+        std::process::exit(2)
+    });
+
+    let succeeded = match options.mode {
+        Mode::Write => write(&options),
+        Mode::Check => check(&options),
+        Mode::Watch => watch(&options),
+    };
+
+    if !succeeded {
+        std::process::exit(1)
+    }
+}
+
+fn report(input: &Path, errors: &[CompileError]) {
+    errors
+        .iter()
+        .for_each(|error| eprintln!("{}: {error}", input.display()));
+    eprintln!("{} problem(s), levels weren't compiled", errors.len());
+}
+
+/// Compiles the map into the output file, returns whether it went through
+fn write(options: &Options) -> bool {
+    let compiled = compile(&options.input).and_then(|levels| {
+        std::fs::write(&options.output, levels)
+            .map_err(|error| vec![Location::default().error(ErrorKind::Write(error))])
+    });
+
+    match compiled {
+        Ok(()) => {
+            println!(
+                "Compiled {} into {}",
+                options.input.display(),
+                options.output.display()
+            );
+            true
+        }
+        Err(errors) => {
+            report(&options.input, &errors);
+            false
+        }
+    }
+}
+
+/// Makes sure the output file is what the map compiles to right now
+fn check(options: &Options) -> bool {
+    let levels = match compile(&options.input) {
+        Ok(levels) => levels,
+        Err(errors) => {
+            report(&options.input, &errors);
+            return false;
+        }
+    };
+
+    if std::fs::read(&options.output).ok().as_ref() != Some(&levels) {
+        eprintln!(
+            "{} is out of date with {}, run map-compiler to regenerate it",
+            options.output.display(),
+            options.input.display()
+        );
+        return false;
+    }
+
+    println!("{} is up to date", options.output.display());
+    true
+}
+
+/// Compiles the map every time it's saved, until the compiler is stopped
+fn watch(options: &Options) -> bool {
+    let modified = || {
+        std::fs::metadata(&options.input)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    };
+    let mut last_compiled: Option<SystemTime> = None;
+
+    println!("Watching {} for changes", options.input.display());
+
+    loop {
+        let current = modified();
+
+        // Editors tend to save in a few steps, so a map that's mid-save just gets caught next time
+        if current.is_some() && current != last_compiled {
+            last_compiled = current;
+            write(options);
+        }
+
+        std::thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// Checks every level in the map can be finished, failing unless each one is known to be. The
+/// map is compiled on the spot, so there's no need to regenerate the levels first.
+///
+/// The shortest solution is only found for small levels. The shipped ones are too big for the
+/// search to finish within `max_states`, so for those it reports how many actions the shortest
+/// solution takes at least and at most instead
+fn solve(input: &Path, max_states: usize) {
+    let compiled = compile(input).unwrap_or_else(|errors| {
+        report(input, &errors);
+        std::process::exit(1)
+    });
+    let levels = levels::decode(&compiled).expect("levels decode right after being encoded");
+    let mut all_solvable = true;
+
+    for level in levels {
+        let initial = LevelState::new((Grid(level.columns), level.spawn), Character::TheCat);
+        let verdict = Solver::new(&initial).solve(&initial, max_states);
+
+        if !verdict.solvable() {
+            all_solvable = false;
+        }
+
+        println!("{}: {verdict}", level.name);
+    }
+
+    if !all_solvable {
+        std::process::exit(1);
+    }
+}
+
+/// Compiles a map into the game's level file, or lists every problem found on the way
+fn compile(input: &Path) -> Result<Vec<u8>, Vec<CompileError>> {
+    let file = File::open(input)
+        .map_err(|error| vec![Location::default().error(ErrorKind::Read(error))])?;
+    let ldtk = serde_json::from_reader::<_, Ldtk>(BufReader::new(file))
+        .map_err(|error| vec![Location::default().error(ErrorKind::Parse(error))])?;
+
+    let tileset = ldtk.defs.tileset(TILESET).map_err(|error| vec![error])?;
+    let mut errors = Vec::new();
 
     let levels = ldtk
         .levels
@@ -211,152 +612,499 @@ fn main() {
         .map(|level| {
             let mut grid: Vec<Vec<Tile>> = Vec::new();
 
-            level.layer_instances[2].grid_tiles.iter().for_each(|tile| {
-                let tags = ldtk.defs.tilesets[0]
-                    .enum_tags
-                    .iter()
-                    .filter(|e| e.tile_ids.contains(&tile.tile))
-                    .map(|e| e.enum_value_id.clone())
-                    .collect::<HashSet<String>>();
+            level
+                .tile_layers(&mut errors)
+                .into_iter()
+                .for_each(|layer| {
+                    let location = Location::level(&level.identifier).layer(&layer.identifier);
 
-                let custom_data = ldtk.defs.tilesets[0]
-                    .custom_data
-                    .iter()
-                    .find(|e| e.tile_id == tile.tile);
+                    if layer.tileset_uid != Some(tileset.uid) {
+                        errors.push(location.error(ErrorKind::WrongTileset(TILESET)));
+                        return;
+                    }
 
-                let (x, y) = (tile.position.0 / 16, tile.position.1 / 16);
+                    layer.tiles().for_each(|tile| {
+                        place_tile(&mut grid, tile, tileset, &location, &mut errors)
+                    });
+                });
 
-                if grid.len() < x + 1 {
-                    grid.extend(std::iter::repeat_n(Vec::new(), x + 1 - grid.len()));
+            match level.entity_layer() {
+                Ok(layer) => {
+                    let location = Location::level(&level.identifier).layer(&layer.identifier);
+                    place_entities(&mut grid, layer, &location, &mut errors);
                 }
+                Err(error) => errors.push(error),
+            }
 
-                let column = &mut grid[x];
+            grid
+        })
+        .collect::<Vec<_>>();
 
-                if column.len() < y + 1 {
-                    column.extend(std::iter::repeat_n(
-                        Default::default(),
-                        y + 1 - column.len(),
-                    ));
-                }
+    let levels = levels
+        .iter()
+        .zip(&ldtk.levels)
+        .map(|(grid, level)| level_definition(grid, level, &mut errors))
+        .collect::<Vec<_>>();
 
-                if let Some(custom_data) = custom_data {
-                    let data = custom_data.data.as_str();
-                    let prev = std::mem::take(&mut column[y]);
-
-                    column[y] = match data {
-                        "BLOOD_2" => Tile {
-                            blood_level: BloodLevel::Grande,
-                            ..prev
-                        },
-                        "BLOOD_1" => Tile {
-                            blood_level: BloodLevel::Tall,
-                            ..prev
-                        },
-                        "BLOOD_3" => Tile {
-                            blood_level: BloodLevel::Venti,
-                            ..prev
-                        },
-                        "BLEACH" => Tile {
-                            item: Item::Bleach,
-                            ..prev
-                        },
-                        "KNIFE" => Tile {
-                            item: Item::Knife,
-                            ..prev
-                        },
-                        "SPONGE" => Tile {
-                            item: Item::Sponge,
-                            ..prev
-                        },
-                        "BAG_ROLL" => Tile {
-                            item: Item::BagRoll,
-                            ..prev
-                        },
-                        "BODY_BAG" => Tile {
-                            item: Item::BodyBag,
-                            ..prev
-                        },
-                        "BAG" => Tile {
-                            item: Item::Bag,
-                            ..prev
-                        },
-                        "BODY" => Tile {
-                            item: Item::Body,
-                            ..prev
-                        },
-                        "DROP_POINT" => Tile {
-                            drop_point: true,
-                            ..prev
-                        },
-                        "SPAWN" => Tile {
-                            player: true,
-                            ..prev
-                        },
-                        value => panic!("Unknown item {value}"),
-                    }
-                }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
 
-                if tags.is_empty() {
-                    return;
-                };
-
-                if tags.contains("Foreground") {
-                    let prev = std::mem::take(&mut column[y]);
-                    column[y] = Tile {
-                        foreground: Some(Vec2 {
-                            x: tile.source.0 / 16,
-                            y: tile.source.1 / 16,
-                        }),
-                        ..prev
-                    };
-                }
+    Ok(levels::encode(&levels))
+}
 
-                if tags.contains("Wall") || tags.contains("Floor") {
-                    let prev = std::mem::take(&mut column[y]);
-                    if tags.contains("Furniture") {
-                        column[y] = Tile {
-                            furniture: if tags.contains("Wall") {
-                                TileBackground::Wall(Vec2 {
-                                    x: tile.source.0 / 16,
-                                    y: tile.source.1 / 16,
-                                })
-                            } else {
-                                TileBackground::Floor(Vec2 {
-                                    x: tile.source.0 / 16,
-                                    y: tile.source.1 / 16,
-                                })
-                            },
-                            ..prev
-                        };
-                    } else {
-                        column[y] = Tile {
-                            background: if tags.contains("Wall") {
-                                TileBackground::Wall(Vec2 {
-                                    x: tile.source.0 / 16,
-                                    y: tile.source.1 / 16,
-                                })
-                            } else {
-                                TileBackground::Floor(Vec2 {
-                                    x: tile.source.0 / 16,
-                                    y: tile.source.1 / 16,
-                                })
-                            },
-                            ..prev
-                        };
-                    }
-                }
-            });
+/// Stacks a tile from one of the layers onto whatever the layers below put in its cell
+fn place_tile(
+    grid: &mut Vec<Vec<Tile>>,
+    tile: &GridTile,
+    tileset: &Tileset,
+    location: &Location,
+    errors: &mut Vec<CompileError>,
+) {
+    let tags = tileset
+        .enum_tags
+        .iter()
+        .filter(|e| e.tile_ids.contains(&tile.tile))
+        .map(|e| e.enum_value_id.clone())
+        .collect::<HashSet<String>>();
 
-            grid
+    let custom_data = tileset.custom_data.iter().find(|e| e.tile_id == tile.tile);
+
+    let (x, y) = (tile.position.0 / 16, tile.position.1 / 16);
+
+    if grid.len() < x + 1 {
+        grid.extend(std::iter::repeat_n(Vec::new(), x + 1 - grid.len()));
+    }
+
+    let column = &mut grid[x];
+
+    if column.len() < y + 1 {
+        column.extend(std::iter::repeat_n(
+            Default::default(),
+            y + 1 - column.len(),
+        ));
+    }
+
+    if let Some(custom_data) = custom_data {
+        let data = custom_data.data.as_str();
+        let prev = std::mem::take(&mut column[y]);
+
+        column[y] = match data {
+            "BLOOD_2" => Tile {
+                blood_level: BloodLevel::Grande,
+                ..prev
+            },
+            "BLOOD_1" => Tile {
+                blood_level: BloodLevel::Tall,
+                ..prev
+            },
+            "BLOOD_3" => Tile {
+                blood_level: BloodLevel::Venti,
+                ..prev
+            },
+            "BLEACH" => Tile {
+                item: Item::Bleach,
+                ..prev
+            },
+            "KNIFE" => Tile {
+                item: Item::Knife,
+                ..prev
+            },
+            "SPONGE" => Tile {
+                item: Item::Sponge,
+                ..prev
+            },
+            "BAG_ROLL" => Tile {
+                item: Item::BagRoll,
+                ..prev
+            },
+            "BODY_BAG" => Tile {
+                item: Item::BodyBag,
+                ..prev
+            },
+            "BAG" => Tile {
+                item: Item::Bag,
+                ..prev
+            },
+            "BODY" => Tile {
+                item: Item::Body(BodyLevel::Start),
+                ..prev
+            },
+            "DROP_POINT" => Tile {
+                drop_point: true,
+                ..prev
+            },
+            "SPAWN" => Tile {
+                spawn: Some(Spawn {
+                    facing: Direction::Down,
+                    character: None,
+                }),
+                ..prev
+            },
+            "SINK" => Tile { sink: true, ..prev },
+            "DOORMAT" => Tile {
+                doormat: true,
+                ..prev
+            },
+            value => {
+                errors.push(
+                    location
+                        .clone()
+                        .pixel(tile.position.0, tile.position.1)
+                        .grid(x, y)
+                        .tile_id(tile.tile)
+                        .error(ErrorKind::UnknownCustomData(value.to_string())),
+                );
+                prev
+            }
+        }
+    }
+
+    if tags.is_empty() {
+        return;
+    };
+
+    if tags.contains("Foreground") {
+        let prev = std::mem::take(&mut column[y]);
+        column[y] = Tile {
+            foreground: Some(Vec2 {
+                x: tile.source.0 / 16,
+                y: tile.source.1 / 16,
+            }),
+            ..prev
+        };
+    }
+
+    if tags.contains("Wall") || tags.contains("Floor") {
+        let prev = std::mem::take(&mut column[y]);
+        if tags.contains("Furniture") {
+            column[y] = Tile {
+                furniture: if tags.contains("Wall") {
+                    TileBackground::Wall(Vec2 {
+                        x: tile.source.0 / 16,
+                        y: tile.source.1 / 16,
+                    })
+                } else {
+                    TileBackground::Floor(Vec2 {
+                        x: tile.source.0 / 16,
+                        y: tile.source.1 / 16,
+                    })
+                },
+                ..prev
+            };
+        } else {
+            column[y] = Tile {
+                background: if tags.contains("Wall") {
+                    TileBackground::Wall(Vec2 {
+                        x: tile.source.0 / 16,
+                        y: tile.source.1 / 16,
+                    })
+                } else {
+                    TileBackground::Floor(Vec2 {
+                        x: tile.source.0 / 16,
+                        y: tile.source.1 / 16,
+                    })
+                },
+                ..prev
+            };
+        }
+    }
+}
+
+/// Puts every entity of the Entities layer on its tile. A Spawn entity takes over from any
+/// SPAWN tile in the level, so moving the spawn over to an entity doesn't leave two behind
+fn place_entities(
+    grid: &mut [Vec<Tile>],
+    layer: &LayerInstance,
+    location: &Location,
+    errors: &mut Vec<CompileError>,
+) {
+    if layer
+        .entity_instances
+        .iter()
+        .any(|entity| entity.identifier == "Spawn")
+    {
+        grid.iter_mut().flatten().for_each(|tile| tile.spawn = None);
+    }
+
+    layer
+        .entity_instances
+        .iter()
+        .for_each(|entity| place_entity(grid, entity, location, errors));
+}
+
+/// Puts an entity from the Entities layer on its tile, the fields it understands are:
+///
+/// - `Body` with an optional `BodyLevel` enum field, from `Start` to `Seven`
+/// - `Blood` with a `Level` int field, 1 for tall, 2 for grande and 3 for venti
+/// - `Spawn` with an optional `Facing` enum field and an optional `Character` enum field, for
+///   levels that insist on who plays them
+/// - `Knife`, `Sponge`, `Bleach`, `BagRoll`, `Bag`, `BodyBag` and `DropPoint` without any
+fn place_entity(
+    grid: &mut [Vec<Tile>],
+    entity: &EntityInstance,
+    location: &Location,
+    errors: &mut Vec<CompileError>,
+) {
+    let (x, y) = entity.grid;
+    let location = location.clone().pixel(entity.px.0, entity.px.1).grid(x, y);
+    let Some(tile) = grid.get_mut(x).and_then(|column| column.get_mut(y)) else {
+        errors.push(location.error(ErrorKind::OutsideLevel));
+        return;
+    };
+    let bad_field = |field, value: &str| {
+        location.clone().error(ErrorKind::BadEntityField {
+            field,
+            value: value.to_string(),
         })
-        .collect::<Vec<_>>();
+    };
 
-    let level_functions = levels
-        .into_iter()
-        .enumerate()
-        .map(|(level_num, grid)| level_function(&grid, level_num))
-        .join("\n");
-    let file = tiles_file(level_functions);
+    match entity.identifier.as_str() {
+        "Body" => {
+            let body_level = match entity.enum_field("BodyLevel") {
+                None | Some("Start") => BodyLevel::Start,
+                Some("One") => BodyLevel::One,
+                Some("Two") => BodyLevel::Two,
+                Some("Three") => BodyLevel::Three,
+                Some("Four") => BodyLevel::Four,
+                Some("Five") => BodyLevel::Five,
+                Some("Six") => BodyLevel::Six,
+                Some("Seven") => BodyLevel::Seven,
+                Some(value) => return errors.push(bad_field("BodyLevel", value)),
+            };
+            tile.item = Item::Body(body_level);
+        }
+        "Blood" => {
+            let Some(value) = entity.field("Level") else {
+                return errors.push(location.error(ErrorKind::MissingEntityField("Level")));
+            };
+            tile.blood_level = match value.as_u64() {
+                Some(1) => BloodLevel::Tall,
+                Some(2) => BloodLevel::Grande,
+                Some(3) => BloodLevel::Venti,
+                _ => return errors.push(bad_field("Level", &value.to_string())),
+            };
+        }
+        "Spawn" => {
+            let facing = match entity.enum_field("Facing") {
+                None | Some("Down") => Direction::Down,
+                Some("Up") => Direction::Up,
+                Some("Left") => Direction::Left,
+                Some("Right") => Direction::Right,
+                Some(value) => return errors.push(bad_field("Facing", value)),
+            };
+            let character = match entity.enum_field("Character") {
+                None => None,
+                Some("TheCat") => Some(Character::TheCat),
+                Some("TwoToes") => Some(Character::TwoToes),
+                Some("MachineGun") => Some(Character::MachineGun),
+                Some("OneEye") => Some(Character::OneEye),
+                Some(value) => return errors.push(bad_field("Character", value)),
+            };
+            tile.spawn = Some(Spawn { facing, character });
+        }
+        "Knife" => tile.item = Item::Knife,
+        "Sponge" => tile.item = Item::Sponge,
+        "Bleach" => tile.item = Item::Bleach,
+        "BagRoll" => tile.item = Item::BagRoll,
+        "Bag" => tile.item = Item::Bag,
+        "BodyBag" => tile.item = Item::BodyBag,
+        "DropPoint" => tile.drop_point = true,
+        name => errors.push(location.error(ErrorKind::UnknownEntity(name.to_string()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(json: &str) -> EntityInstance {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn entities_configure_their_tile() {
+        let mut grid = vec![vec![Tile::default(); 2]; 2];
+        let mut errors = Vec::new();
+
+        place_entity(
+            &mut grid,
+            &entity(
+                r#"{ "__identifier": "Body", "__grid": [0, 1], "px": [0, 16], "fieldInstances": [
+                    { "__identifier": "BodyLevel", "__value": "Four" }
+                ] }"#,
+            ),
+            &Location::level("Test"),
+            &mut errors,
+        );
+        place_entity(
+            &mut grid,
+            &entity(
+                r#"{ "__identifier": "Spawn", "__grid": [1, 0], "px": [16, 0], "fieldInstances": [
+                    { "__identifier": "Facing", "__value": "Left" },
+                    { "__identifier": "Character", "__value": null }
+                ] }"#,
+            ),
+            &Location::level("Test"),
+            &mut errors,
+        );
+
+        assert!(errors.is_empty());
+        assert!(matches!(grid[0][1].item, Item::Body(BodyLevel::Four)));
+        assert!(matches!(
+            grid[1][0].spawn,
+            Some(Spawn {
+                facing: Direction::Left,
+                character: None
+            })
+        ));
+    }
+
+    #[test]
+    fn every_bad_entity_is_reported_with_where_it_is() {
+        let mut grid = vec![vec![Tile::default()]];
+        let mut errors = Vec::new();
+        let location = Location::level("Test").layer("Entities");
+
+        place_entity(
+            &mut grid,
+            &entity(
+                r#"{ "__identifier": "Blood", "__grid": [0, 0], "px": [0, 0], "fieldInstances": [
+                    { "__identifier": "Level", "__value": 4 }
+                ] }"#,
+            ),
+            &location,
+            &mut errors,
+        );
+        place_entity(
+            &mut grid,
+            &entity(
+                r#"{ "__identifier": "Blood", "__grid": [0, 0], "px": [0, 0], "fieldInstances": [
+                    { "__identifier": "Level", "__value": null }
+                ] }"#,
+            ),
+            &location,
+            &mut errors,
+        );
+        place_entity(
+            &mut grid,
+            &entity(
+                r#"{ "__identifier": "Mop", "__grid": [3, 2], "px": [48, 32], "fieldInstances": [] }"#,
+            ),
+            &location,
+            &mut errors,
+        );
+
+        assert_eq!(
+            errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "Test, Entities layer, pixel 0, 0, tile 0, 0: unknown Level 4",
+                "Test, Entities layer, pixel 0, 0, tile 0, 0: no Level field on the entity",
+                "Test, Entities layer, pixel 48, 32, tile 3, 2: entity is outside the level",
+            ]
+        );
+    }
+
+    fn spawn_tile() -> Tile {
+        Tile {
+            background: TileBackground::Floor(Vec2::default()),
+            spawn: Some(Spawn {
+                facing: Direction::Down,
+                character: None,
+            }),
+            ..Tile::default()
+        }
+    }
+
+    #[test]
+    fn a_spawn_entity_takes_over_from_spawn_tiles() {
+        let mut grid = vec![vec![spawn_tile()], vec![Tile::default()]];
+        let mut errors = Vec::new();
+        let layer: LayerInstance = serde_json::from_str(
+            r#"{ "__identifier": "Entities", "__tilesetDefUid": null, "gridTiles": [],
+                "autoLayerTiles": [], "entityInstances": [
+                    { "__identifier": "Spawn", "__grid": [1, 0], "px": [16, 0], "fieldInstances": [] }
+                ] }"#,
+        )
+        .unwrap();
+
+        place_entities(&mut grid, &layer, &Location::level("Test"), &mut errors);
+
+        assert!(errors.is_empty());
+        assert!(grid[0][0].spawn.is_none());
+        assert!(grid[1][0].spawn.is_some());
+    }
+
+    #[test]
+    fn levels_have_a_single_spawn_with_the_cleaners_on_it() {
+        let level: Level = serde_json::from_str(
+            r#"{ "identifier": "Test", "layerInstances": [], "fieldInstances": [
+                { "__identifier": "ThreeStarPar", "__value": 10 },
+                { "__identifier": "TwoStarPar", "__value": 20 }
+            ] }"#,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+
+        let definition = level_definition(
+            &[vec![spawn_tile()], vec![spawn_tile()]],
+            &level,
+            &mut errors,
+        );
+
+        assert_eq!(
+            errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["Test, pixel 16, 0, tile 1, 0: another spawn point, a level only has the one"]
+        );
+        assert_eq!(definition.spawn.position, game::vec2(0, 0));
+        assert!(definition.columns[0][0].player);
+        assert!(!definition.columns[1][0].player);
+    }
+
+    #[test]
+    fn shipped_levels_are_what_the_map_compiles_to() {
+        let compiled = compile(Path::new(DEFAULT_INPUT)).unwrap();
+
+        // Not `assert_eq!`, a mismatch would print both files byte by byte
+        assert!(
+            std::fs::read(DEFAULT_OUTPUT).unwrap() == compiled,
+            "src/levels.bin is out of date with Cleaners.ldtk, run map-compiler to regenerate it"
+        );
+    }
+
+    fn options(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn options_default_to_the_map_next_to_the_compiler() {
+        assert_eq!(
+            options(&[]),
+            Ok(Options {
+                input: PathBuf::from(DEFAULT_INPUT),
+                output: PathBuf::from(DEFAULT_OUTPUT),
+                mode: Mode::Write,
+            })
+        );
+        assert_eq!(
+            options(&["map.ldtk", "--output", "levels.bin", "--watch"]),
+            Ok(Options {
+                input: PathBuf::from("map.ldtk"),
+                output: PathBuf::from("levels.bin"),
+                mode: Mode::Watch,
+            })
+        );
+    }
 
-    std::fs::write("../src/tiles.rs", file).unwrap();
+    #[test]
+    fn options_reject_conflicting_modes_and_extra_maps() {
+        assert!(options(&["--check", "--watch"]).is_err());
+        assert!(options(&["--watch", "--check"]).is_err());
+        assert_eq!(
+            options(&["--check", "--check"]).map(|options| options.mode),
+            Ok(Mode::Check)
+        );
+        assert!(options(&["a.ldtk", "b.ldtk"]).is_err());
+        assert!(options(&["--output"]).is_err());
+    }
 }
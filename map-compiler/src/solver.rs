@@ -0,0 +1,609 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::rc::Rc;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use the_cleaners::level::{
+    vec2, Action, BloodLevel, BodyAction, Direction, Furniture, IVec2, Item, LevelFinished,
+    LevelState, Tile, Vec2, BODY_CHOPPING_TIME, CLEANING_TIME,
+};
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// How the open states are ordered, from a state's actions so far and its heuristic
+#[derive(Clone, Copy)]
+enum Strategy {
+    /// A*, the first solution found is the shortest one
+    Optimal,
+    /// Always carry on from the state closest to done, finds a solution fast but not the shortest
+    Greedy,
+}
+
+impl Strategy {
+    fn priority(self, cost: usize, heuristic: usize) -> usize {
+        match self {
+            Strategy::Optimal => cost + heuristic,
+            Strategy::Greedy => heuristic,
+        }
+    }
+}
+
+pub enum Verdict {
+    Optimal {
+        actions: usize,
+        states: usize,
+    },
+    // The shortest solution takes somewhere between the two
+    Solvable {
+        at_least: usize,
+        at_most: usize,
+        states: usize,
+    },
+    Unsolvable {
+        states: usize,
+    },
+    Unknown {
+        states: usize,
+    },
+}
+
+impl Verdict {
+    /// Whether the level is known to be finishable
+    pub fn solvable(&self) -> bool {
+        matches!(self, Verdict::Optimal { .. } | Verdict::Solvable { .. })
+    }
+}
+
+/// How a single search ended, `states` is how many it came across
+enum Search {
+    Solved { actions: usize, states: usize },
+    Exhausted { states: usize },
+    // No solution takes fewer than `lower_bound` actions
+    GaveUp { lower_bound: usize },
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Optimal { actions, states } => {
+                write!(
+                    f,
+                    "solvable in {actions} actions (optimal, {states} states)"
+                )
+            }
+            Verdict::Solvable {
+                at_least,
+                at_most,
+                states,
+            } => write!(
+                f,
+                "solvable in {at_least} to {at_most} actions (gave up on the shortest after \
+                 {states} states)"
+            ),
+            Verdict::Unsolvable { states } => {
+                write!(f, "UNSOLVABLE (explored all {states} states)")
+            }
+            Verdict::Unknown { states } => write!(
+                f,
+                "UNKNOWN (gave up after {states} states, try a bigger --max-states)"
+            ),
+        }
+    }
+}
+
+/// Everything about a [`LevelState`] that can change while solving, small enough to keep
+/// millions of them around. Only the tiles whose item or blood differs from the start of the
+/// level are listed
+#[derive(BorshSerialize, BorshDeserialize)]
+struct Key {
+    position: (u16, u16),
+    facing: Direction,
+    inventory: Item,
+    blood_on_boots: BloodLevel,
+    changes: Vec<(u16, u16, Item, BloodLevel)>,
+}
+
+/// Searches for the shortest way for a single cleaner to finish a level, using the game's own
+/// rules.
+///
+/// An action is a single move, a drop, or a whole held interaction (a full chop, bag, clean or
+/// pickup). To keep the search small, the cleaner is held to a few habits:
+///
+/// - walking with clean boots is collapsed into a single jump to wherever something can be done,
+///   counting every step of the way
+/// - knives, sponges and bleach are only ever put down where one of their kind started out
+/// - bags are only ever let go of by filling them, and body bags by dropping them onto drop
+///   points
+///
+/// Every solution found is made of moves a player could make, so a level reported solvable really
+/// is. The habits can rule out a shorter solution though, so optimal means the shortest that keeps
+/// to them.
+pub struct Solver {
+    // The level as it starts out, without the cleaner in it
+    base: LevelState,
+    // Where each knife, sponge and bleach starts out
+    homes: Vec<(Vec2, Item)>,
+}
+
+impl Solver {
+    pub fn new(initial: &LevelState) -> Self {
+        let homes = initial
+            .grid
+            .iter()
+            .enumerate()
+            .flat_map(|(x, column)| {
+                column
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, tile)| has_home(tile.item))
+                    .map(move |(y, tile)| (vec2(x, y), tile.item))
+            })
+            .collect();
+
+        let mut base = initial.clone();
+        base.grid.0.iter_mut().flatten().for_each(|tile| {
+            tile.player = false;
+        });
+
+        Solver { base, homes }
+    }
+
+    /// Finds any solution first, to know the level can be finished at all, then looks for a
+    /// shorter one. Each search gives up once it has come across more than `max_states` states,
+    /// which is what bounds how much memory solving takes
+    pub fn solve(&self, initial: &LevelState, max_states: usize) -> Verdict {
+        let at_most = match self.search(initial, Strategy::Greedy, usize::MAX, max_states) {
+            Search::Solved { actions, .. } => actions,
+            Search::Exhausted { states } => return Verdict::Unsolvable { states },
+            Search::GaveUp { .. } => return Verdict::Unknown { states: max_states },
+        };
+
+        match self.search(initial, Strategy::Optimal, at_most, max_states) {
+            Search::Solved { actions, states } => Verdict::Optimal { actions, states },
+            // Nothing is shorter than the solution already found
+            Search::Exhausted { states } => Verdict::Optimal {
+                actions: at_most,
+                states,
+            },
+            Search::GaveUp { lower_bound } => Verdict::Solvable {
+                at_least: lower_bound,
+                at_most,
+                states: max_states,
+            },
+        }
+    }
+
+    /// Leaves out anything that can't be finished in `bound` actions
+    fn search(
+        &self,
+        initial: &LevelState,
+        strategy: Strategy,
+        bound: usize,
+        max_states: usize,
+    ) -> Search {
+        // Shared between the two so that every state is only kept once
+        let mut best = HashMap::<Rc<[u8]>, usize>::new();
+        let mut open = BinaryHeap::new();
+
+        let key = Rc::<[u8]>::from(self.encode(initial));
+        best.insert(key.clone(), 0);
+        open.push(Reverse((
+            strategy.priority(0, heuristic(initial)),
+            0,
+            Some(key),
+        )));
+
+        while let Some(Reverse((priority, cost, key))) = open.pop() {
+            let Some(key) = key else {
+                return Search::Solved {
+                    actions: cost,
+                    states: best.len(),
+                };
+            };
+
+            if best.get(&key).is_some_and(|&known| known < cost) {
+                continue;
+            }
+
+            let state = self.decode(&key);
+            for (next, actions) in self.successors(&state) {
+                let cost = cost + actions;
+
+                let LevelFinished::No = next.1 else {
+                    if cost <= bound {
+                        open.push(Reverse((strategy.priority(cost, 0), cost, None)));
+                    }
+                    continue;
+                };
+
+                let heuristic = heuristic(&next.0);
+                if cost + heuristic > bound {
+                    continue;
+                }
+
+                let key = self.encode(&next.0);
+                if best.get(key.as_slice()).is_some_and(|&known| known <= cost) {
+                    continue;
+                }
+
+                let key = Rc::<[u8]>::from(key);
+                best.insert(key.clone(), cost);
+                open.push(Reverse((
+                    strategy.priority(cost, heuristic),
+                    cost,
+                    Some(key),
+                )));
+
+                if best.len() > max_states {
+                    // Everything left to look at is at least as far from done as the state
+                    // being expanded, or as the best of what's still open
+                    let lower_bound = open
+                        .peek()
+                        .map_or(priority, |Reverse((open, _, _))| priority.min(*open));
+                    return Search::GaveUp { lower_bound };
+                }
+            }
+        }
+
+        Search::Exhausted { states: best.len() }
+    }
+
+    fn successors(&self, state: &LevelState) -> Vec<((LevelState, LevelFinished), usize)> {
+        let clean_boots = state.cleaners[0].blood_on_boots == BloodLevel::None;
+        let spots = if clean_boots {
+            reachable(state)
+        } else {
+            vec![(state.cleaners[0].position, state.cleaners[0].facing, 0)]
+        };
+
+        let mut successors = Vec::new();
+        let mut push = |next: LevelState, finished: LevelFinished, actions: usize| {
+            successors.push(((next, finished), actions));
+        };
+
+        for (position, facing, distance) in spots {
+            let Some(front_position) = state.grid.offset(position, IVec2::from(facing)) else {
+                continue;
+            };
+            let front = &state.grid[front_position];
+            let worth_interacting = match front.item {
+                Item::None => false,
+                Item::Body(level, _) => match level.action() {
+                    BodyAction::Chop => state.cleaners[0].inventory == Item::Knife,
+                    BodyAction::Bag => state.cleaners[0].inventory == Item::Bag,
+                },
+                _ => state.cleaners[0].inventory == Item::None,
+            } || (state.cleaners[0].inventory.can_clean()
+                && front.blood_level != BloodLevel::None)
+                || (matches!(front.furniture, Furniture::Sink(_))
+                    && matches!(state.cleaners[0].inventory, Item::Sponge(used) if used > 0));
+            let inventory = state.cleaners[0].inventory;
+            let worth_dropping = match inventory {
+                Item::BodyBag => front.drop_point,
+                _ => {
+                    has_home(inventory)
+                        && front.item == Item::None
+                        && self.homes.iter().any(|&(home, item)| {
+                            home == front_position && same_kind(item, inventory)
+                        })
+                }
+            };
+
+            if !worth_interacting && !worth_dropping && !soaks_boots(front) {
+                continue;
+            }
+
+            // Each of these always changes the level, so there's no need to check for no-ops
+            if worth_interacting {
+                let mut next = teleport(state, position, facing);
+                let finished = interact_fully(&mut next);
+                push(next, finished, distance + 1);
+            }
+
+            if worth_dropping {
+                let mut next = teleport(state, position, facing);
+                let finished = next.apply(0, Action::Drop);
+                push(next, finished, distance + 1);
+            }
+
+            // Stepping in a body or blood dirties the boots, so it is never part of a collapsed walk
+            if clean_boots && soaks_boots(front) && front.walkable() {
+                let mut next = teleport(state, position, facing);
+                let finished = next.apply(0, Action::Move(facing));
+                push(next, finished, distance + 1);
+            }
+        }
+
+        if !clean_boots {
+            for direction in DIRECTIONS {
+                let mut next = state.clone();
+                let finished = next.apply(0, Action::Move(direction));
+                if (next.cleaners[0].position, next.cleaners[0].facing)
+                    != (state.cleaners[0].position, state.cleaners[0].facing)
+                {
+                    push(next, finished, 1);
+                }
+            }
+        }
+
+        successors
+    }
+
+    fn encode(&self, state: &LevelState) -> Vec<u8> {
+        let changes = state
+            .grid
+            .iter()
+            .zip(self.base.grid.iter())
+            .enumerate()
+            .flat_map(|(x, (column, start))| {
+                column
+                    .iter()
+                    .zip(start)
+                    .enumerate()
+                    .filter_map(move |(y, (tile, start))| {
+                        if (tile.item, tile.blood_level) == (start.item, start.blood_level) {
+                            return None;
+                        }
+
+                        Some((x as u16, y as u16, tile.item, tile.blood_level))
+                    })
+            })
+            .collect();
+
+        Key {
+            position: (
+                state.cleaners[0].position.x as u16,
+                state.cleaners[0].position.y as u16,
+            ),
+            facing: state.cleaners[0].facing,
+            inventory: state.cleaners[0].inventory,
+            blood_on_boots: state.cleaners[0].blood_on_boots,
+            changes,
+        }
+        .try_to_vec()
+        .expect("Serializing into memory can't fail")
+    }
+
+    fn decode(&self, key: &[u8]) -> LevelState {
+        let key = Key::try_from_slice(key).expect("Keys are only made by encode");
+        let mut state = self.base.clone();
+
+        key.changes
+            .into_iter()
+            .for_each(|(x, y, item, blood_level)| {
+                let tile = &mut state.grid[vec2(x as usize, y as usize)];
+                tile.item = item;
+                tile.blood_level = blood_level;
+            });
+
+        let position = vec2(key.position.0 as usize, key.position.1 as usize);
+        state.grid[position].player = true;
+        state.cleaners[0].position = position;
+        state.cleaners[0].facing = key.facing;
+        state.cleaners[0].inventory = key.inventory;
+        state.cleaners[0].blood_on_boots = key.blood_on_boots;
+        state.recount_objectives();
+
+        state
+    }
+}
+
+/// Every spot and facing the player can walk to without getting blood on their boots, with the
+/// number of moves it takes to get there
+fn reachable(state: &LevelState) -> Vec<(Vec2, Direction, usize)> {
+    let height = state.grid.height();
+    let visit = |position: Vec2, facing: Direction| {
+        (position.x * height + position.y) * 4 + facing as usize
+    };
+
+    let mut visited = vec![false; state.grid.width() * height * 4];
+    visited[visit(state.cleaners[0].position, state.cleaners[0].facing)] = true;
+    let mut queue = VecDeque::from([(state.cleaners[0].position, state.cleaners[0].facing, 0)]);
+    let mut spots = Vec::new();
+
+    while let Some((position, facing, distance)) = queue.pop_front() {
+        spots.push((position, facing, distance));
+
+        for direction in DIRECTIONS {
+            let next = match state.grid.offset(position, IVec2::from(direction)) {
+                Some(target)
+                    if state.grid[target].walkable() && !soaks_boots(&state.grid[target]) =>
+                {
+                    target
+                }
+                _ => position,
+            };
+
+            if !std::mem::replace(&mut visited[visit(next, direction)], true) {
+                queue.push_back((next, direction, distance + 1));
+            }
+        }
+    }
+
+    spots
+}
+
+fn soaks_boots(tile: &Tile) -> bool {
+    matches!(tile.item, Item::Body(_, _)) || tile.blood_level != BloodLevel::None
+}
+
+/// Tools that are put back where they started, so how worn they are carries over to the next
+/// pick up
+fn has_home(item: Item) -> bool {
+    item == Item::Knife || item.cleaning_tool().is_some()
+}
+
+fn same_kind(a: Item, b: Item) -> bool {
+    std::mem::discriminant(&a) == std::mem::discriminant(&b)
+}
+
+fn teleport(state: &LevelState, position: Vec2, facing: Direction) -> LevelState {
+    let mut state = state.clone();
+    let previous_position = state.cleaners[0].position;
+    state.grid[previous_position].player = false;
+    state.grid[position].player = true;
+    state.cleaners[0].position = position;
+    state.cleaners[0].facing = facing;
+
+    state
+}
+
+/// Holds the interact button until whatever was started in front of the player is done
+fn interact_fully(state: &mut LevelState) -> LevelFinished {
+    for _ in 0..BODY_CHOPPING_TIME.max(CLEANING_TIME) {
+        if let LevelFinished::Yes = state.apply(0, Action::Interact) {
+            return LevelFinished::Yes;
+        }
+
+        let Some(front) = state.in_front_of(0).map(|front| &state.grid[front]) else {
+            break;
+        };
+        let chopping =
+            matches!(front.item, Item::Body(_, progress) if progress != BODY_CHOPPING_TIME);
+        let cleaning = matches!(
+            front.blood_level,
+            BloodLevel::Tall(progress) | BloodLevel::Grande(progress) | BloodLevel::Venti(progress)
+                if progress != CLEANING_TIME
+        );
+
+        if !chopping && !cleaning {
+            break;
+        }
+    }
+
+    LevelFinished::No
+}
+
+/// A lower bound on the actions left, counting only the interactions and drops that can't be
+/// avoided and none of the walking
+fn heuristic(state: &LevelState) -> usize {
+    let in_hands = match state.cleaners[0].inventory {
+        Item::BodyBag => 1,
+        // Has to be rinsed or swapped before it's any use again
+        Item::Sponge(_) if !state.cleaners[0].inventory.can_clean() => 1,
+        _ => 0,
+    };
+
+    in_hands
+        + state
+            .grid
+            .iter()
+            .flatten()
+            .map(|tile| {
+                let blood = match tile.blood_level {
+                    BloodLevel::None => 0,
+                    BloodLevel::Tall(_) => 1,
+                    BloodLevel::Grande(_) => 2,
+                    BloodLevel::Venti(_) => 3,
+                };
+
+                let body = match tile.item {
+                    Item::Body(level, _) => {
+                        std::iter::successors(Some(level), |level| level.lower())
+                            .map(|stage| match stage.action() {
+                                BodyAction::Chop => 1,
+                                // Each bag has to be fetched, filled and delivered
+                                BodyAction::Bag => 3,
+                            })
+                            .sum()
+                    }
+                    Item::BodyBag => 2,
+                    _ => 0,
+                };
+
+                blood + body
+            })
+            .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use the_cleaners::sim::parse;
+
+    fn solve(ascii: &str) -> Verdict {
+        let initial = parse(ascii);
+        Solver::new(&initial).solve(&initial, 10_000)
+    }
+
+    #[test]
+    fn finds_the_shortest_solution() {
+        let verdict = solve(
+            "
+            #####
+            #@.1#
+            #S###
+            #####
+            ",
+        );
+
+        // Pick up the sponge, step right and clean
+        assert!(matches!(verdict, Verdict::Optimal { actions: 3, .. }));
+    }
+
+    #[test]
+    fn rinses_the_sponge_when_there_is_too_much_blood_for_one_go() {
+        let verdict = solve(
+            "
+            ######
+            #@333#
+            #SW###
+            ######
+            ",
+        );
+
+        assert!(verdict.solvable());
+    }
+
+    #[test]
+    fn puts_the_knife_back_to_free_its_hands_for_a_bag() {
+        let verdict = solve(
+            "
+            #########
+            #K.....D#
+            #R@..B..#
+            #.......#
+            #.......#
+            #########
+            ",
+        );
+
+        assert!(verdict.solvable());
+    }
+
+    #[test]
+    fn gives_up_once_out_of_states() {
+        let initial = parse(
+            "
+            ######
+            #@333#
+            #SW###
+            ######
+            ",
+        );
+
+        assert!(matches!(
+            Solver::new(&initial).solve(&initial, 5),
+            Verdict::Unknown { states: 5 }
+        ));
+    }
+
+    #[test]
+    fn blood_without_anything_to_clean_it_is_unsolvable() {
+        let verdict = solve(
+            "
+            #####
+            #@.1#
+            #R###
+            #####
+            ",
+        );
+
+        assert!(matches!(verdict, Verdict::Unsolvable { .. }));
+    }
+}
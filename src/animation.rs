@@ -0,0 +1,137 @@
+//! Sprite animations, declared in `sprites/animations.txt` right next to the sheet they cut up
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::level::{vec2, Character, Direction, Vec2};
+
+const ANIMATIONS: &str = include_str!("../sprites/animations.txt");
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Frame {
+    // A cell in assets.png
+    pub sprite: Vec2,
+    // In pixels, on top of wherever the animation is drawn
+    pub nudge: (i32, i32),
+    // In ticks
+    pub duration: usize,
+}
+
+/// A looping sequence of frames
+#[derive(PartialEq, Debug, Clone)]
+pub struct Animation {
+    frames: Vec<Frame>,
+}
+
+impl Animation {
+    /// The frame showing `tick` ticks into the animation, wrapping around at the end
+    pub fn frame(&self, tick: usize) -> &Frame {
+        let mut tick = tick % self.duration();
+
+        self.frames
+            .iter()
+            .find(|frame| {
+                let showing = tick < frame.duration;
+                tick = tick.saturating_sub(frame.duration);
+                showing
+            })
+            .expect("unreachable")
+    }
+
+    /// How many ticks one loop takes
+    pub fn duration(&self) -> usize {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+}
+
+/// What a character is up to, each with its own animation per direction
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Pose {
+    Idle,
+    Walk,
+}
+
+/// Parses animations, one per line as a name followed by its frames.
+///
+/// A frame is a cell in assets.png as `x,y`, an optional pixel nudge as `/x,y` and how many ticks
+/// it stays up for as `*ticks`, so `4,0/0,-1*8` is the sprite at 4, 0 drawn a pixel higher for 8
+/// ticks. Blank lines and lines starting with `#` are ignored.
+pub fn parse(source: &str) -> HashMap<String, Animation> {
+    source
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            let mut words = line.split_whitespace();
+            let name = words.next().expect("unreachable");
+            let frames = words
+                .map(|frame| {
+                    parse_frame(frame).unwrap_or_else(|| {
+                        panic!("Bad frame {frame:?} for {name} on line {line_number}")
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            if frames.is_empty() {
+                panic!("{name} on line {line_number} has no frames");
+            }
+
+            (name.to_string(), Animation { frames })
+        })
+        .collect()
+}
+
+fn parse_frame(frame: &str) -> Option<Frame> {
+    let (frame, duration) = frame.split_once('*')?;
+    let (sprite, nudge) = match frame.split_once('/') {
+        Some((sprite, nudge)) => (sprite, Some(nudge)),
+        None => (frame, None),
+    };
+    let (x, y) = sprite.split_once(',')?;
+    let nudge = match nudge {
+        Some(nudge) => {
+            let (x, y) = nudge.split_once(',')?;
+            (x.parse().ok()?, y.parse().ok()?)
+        }
+        None => (0, 0),
+    };
+    let duration = duration.parse().ok().filter(|&duration| duration > 0)?;
+
+    Some(Frame {
+        sprite: vec2(x.parse().ok()?, y.parse().ok()?),
+        nudge,
+        duration,
+    })
+}
+
+/// Looks up one of the animations in `sprites/animations.txt`
+pub fn animation(name: &str) -> &'static Animation {
+    static PARSED: OnceLock<HashMap<String, Animation>> = OnceLock::new();
+
+    PARSED
+        .get_or_init(|| parse(ANIMATIONS))
+        .get(name)
+        .unwrap_or_else(|| panic!("No animation named {name}"))
+}
+
+/// A character's animation for facing `direction`, facing left is facing right flipped
+pub fn character(character: Character, pose: Pose, direction: Direction) -> &'static Animation {
+    let character = match character {
+        Character::TheCat => "the_cat",
+        Character::TwoToes => "two_toes",
+        Character::MachineGun => "machine_gun",
+        Character::OneEye => "one_eye",
+    };
+    let pose = match pose {
+        Pose::Idle => "idle",
+        Pose::Walk => "walk",
+    };
+    let direction = match direction {
+        Direction::Up => "up",
+        Direction::Down => "down",
+        Direction::Left | Direction::Right => "right",
+    };
+
+    animation(&format!("{character}.{pose}.{direction}"))
+}
@@ -0,0 +1,115 @@
+//! Where the level is looked at from, trailing after the cleaners and zooming out to keep them
+//! all in frame, without showing the void past the edges of the grid
+
+use turbo::borsh::{self, *};
+
+/// Half the width and height of the box in the middle of the screen the cleaners can roam
+/// without the camera moving, in pixels
+pub const DEAD_ZONE: (f32, f32) = (24.0, 16.0);
+/// How much of the way to where it should be the camera moves every tick
+pub const SMOOTHING: f32 = 0.15;
+/// Room kept between the cleaners and the edges of the screen, in pixels
+pub const MARGIN: f32 = 32.0;
+/// The furthest the camera zooms out to fit cleaners that wander apart, any further and the
+/// level gets too small to play
+pub const MIN_ZOOM: f32 = 0.5;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct Camera {
+    // The center of the view, in pixels
+    pub x: f32,
+    pub y: f32,
+    // How many screen pixels a level pixel takes up, less than 1 zooms out
+    pub zoom: f32,
+    // Zoomed out to show the whole level at once
+    pub overview: bool,
+    // Whether the camera has been pointed at anything yet, so a fresh level doesn't pan in
+    pub placed: bool,
+
+    // Settings:
+    pub dead_zone: (f32, f32),
+    pub smoothing: f32,
+    pub margin: f32,
+    pub min_zoom: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Camera {
+            x: 0.0,
+            y: 0.0,
+            zoom: 1.0,
+            overview: false,
+            placed: false,
+            dead_zone: DEAD_ZONE,
+            smoothing: SMOOTHING,
+            margin: MARGIN,
+            min_zoom: MIN_ZOOM,
+        }
+    }
+}
+
+impl Camera {
+    /// Moves a tick closer to keeping the middle of `targets` inside the dead zone and all of
+    /// them on screen, or to the whole level in overview. `level` and `view` are the size of the
+    /// level and the screen, in pixels
+    pub fn follow(&mut self, targets: &[(f32, f32)], level: (f32, f32), view: (f32, f32)) {
+        let Some(&first) = targets.first() else {
+            return;
+        };
+        let (min, max) = targets.iter().fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        });
+        let target = ((min.0 + max.0) / 2.0, (min.1 + max.1) / 2.0);
+
+        // A fresh camera starts right on the target rather than at the edge of the dead zone
+        if !self.placed {
+            (self.x, self.y) = target;
+        }
+
+        let (x, y, zoom) = if self.overview {
+            let zoom = (view.0 / level.0).min(view.1 / level.1).min(1.0);
+            (level.0 / 2.0, level.1 / 2.0, zoom)
+        } else {
+            let framed = (
+                max.0 - min.0 + 2.0 * self.margin,
+                max.1 - min.1 + 2.0 * self.margin,
+            );
+            (
+                within_dead_zone(self.x, target.0, self.dead_zone.0),
+                within_dead_zone(self.y, target.1, self.dead_zone.1),
+                (view.0 / framed.0)
+                    .min(view.1 / framed.1)
+                    .clamp(self.min_zoom, 1.0),
+            )
+        };
+
+        let smoothing = if self.placed { self.smoothing } else { 1.0 };
+        self.zoom += (zoom - self.zoom) * smoothing;
+        self.x = clamp_to_level(
+            self.x + (x - self.x) * smoothing,
+            level.0,
+            view.0 / self.zoom,
+        );
+        self.y = clamp_to_level(
+            self.y + (y - self.y) * smoothing,
+            level.1,
+            view.1 / self.zoom,
+        );
+        self.placed = true;
+    }
+}
+
+/// The closest center to `center` that keeps `target` no further than `dead_zone` from it
+fn within_dead_zone(center: f32, target: f32, dead_zone: f32) -> f32 {
+    center.clamp(target - dead_zone, target + dead_zone)
+}
+
+/// Keeps the view inside the level, or centers the level when it's smaller than the view
+fn clamp_to_level(center: f32, level: f32, view: f32) -> f32 {
+    if level <= view {
+        level / 2.0
+    } else {
+        center.clamp(view / 2.0, level - view / 2.0)
+    }
+}
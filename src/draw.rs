@@ -0,0 +1,336 @@
+use turbo::prelude::*;
+
+use crate::animation::{self, Animation, Pose};
+use crate::level::{
+    ivec2, vec2, BloodLevel, BodyLevel, Cleaner, Direction, Furniture, Item, LevelState,
+    TileBackground, Vec2, BODY_CHOPPING_TIME, CLEANING_TIME,
+};
+use crate::FRAMES_BETWEEN_MOVES;
+
+pub const CELL_SIZE: usize = 16;
+const PROGRESS_BAR_SIZE: usize = CELL_SIZE - 4;
+
+impl Item {
+    /// What a held item does while it's being used on the tile in front
+    fn work_animation(&self) -> Option<&'static Animation> {
+        match self {
+            Item::Knife => Some("knife.chop"),
+            Item::Sponge(_) => Some("sponge.scrub"),
+            Item::Bleach(_) => Some("bleach.scrub"),
+            Item::Bag => Some("bag.fill"),
+            _ => None,
+        }
+        .map(animation::animation)
+    }
+
+    fn draw(&self, location: Vec2, flip: bool, nudge: (i32, i32)) {
+        let asset = |sprite, location| asset(sprite, location).nudge(nudge);
+        match self {
+            Item::None => {}
+            Item::Body(level, prgrss) => {
+                match level {
+                    BodyLevel::Start => {
+                        asset(vec2(8, 0), location).draw();
+                    }
+                    BodyLevel::One => asset(vec2(13, 0), location).draw(),
+                    BodyLevel::Two => {
+                        asset(vec2(13, 0), location).draw();
+                        asset(vec2(14, 0), location).draw();
+                    }
+                    BodyLevel::Three => {
+                        asset(vec2(13, 0), location).draw();
+                        asset(vec2(14, 0), location).draw();
+                        asset(vec2(15, 0), location).draw();
+                    }
+                    BodyLevel::Four => {
+                        asset(vec2(13, 0), location).draw();
+                        asset(vec2(14, 0), location).draw();
+                        asset(vec2(15, 0), location).draw();
+                        asset(vec2(16, 0), location).draw();
+                    }
+                    BodyLevel::Five => {
+                        asset(vec2(14, 0), location).draw();
+                        asset(vec2(15, 0), location).draw();
+                        asset(vec2(16, 0), location).draw();
+                    }
+                    BodyLevel::Six => {
+                        asset(vec2(15, 0), location).draw();
+                        asset(vec2(16, 0), location).draw();
+                    }
+                    BodyLevel::Seven => {
+                        asset(vec2(16, 0), location).draw();
+                    }
+                }
+                progress(location, (*prgrss as f32) / (BODY_CHOPPING_TIME as f32));
+            }
+            Item::Knife => asset(vec2(6, 2), location).flip_x(flip).draw(),
+            Item::Sponge(_) => {
+                asset(vec2(7, 2), location).flip_x(flip).draw();
+
+                // Soaked through, time to find a sink
+                if !self.can_clean() {
+                    animated(animation::animation("sponge.drip"), tick(), location)
+                        .flip_x(flip)
+                        .draw();
+                }
+            }
+            Item::Bleach(_) => asset(vec2(4, 2), location).flip_x(flip).draw(),
+            Item::Bag => asset(vec2(9, 3), location).flip_x(flip).draw(),
+            Item::BagRoll => asset(vec2(9, 2), location).flip_x(flip).draw(),
+            Item::BodyBag => {
+                if let Some(above) = location.checked_add(ivec2(0, -1)) {
+                    asset(vec2(8, 2), above).flip_x(flip).draw();
+                }
+                asset(vec2(8, 3), location).flip_x(flip).draw()
+            }
+        }
+    }
+}
+
+impl BloodLevel {
+    fn draw(&self, location: Vec2) {
+        match self {
+            BloodLevel::None => unreachable!(),
+            level @ (BloodLevel::Tall(prgrss)
+            | BloodLevel::Grande(prgrss)
+            | BloodLevel::Venti(prgrss)) => {
+                if *prgrss != CLEANING_TIME {
+                    progress(location, (*prgrss as f32) / (CLEANING_TIME as f32));
+                }
+
+                asset(
+                    match level {
+                        BloodLevel::None => unreachable!(),
+                        BloodLevel::Tall(_) => vec2(7, 1),
+                        BloodLevel::Grande(_) => vec2(5, 1),
+                        BloodLevel::Venti(_) => vec2(6, 1),
+                    },
+                    location,
+                )
+                .draw()
+            }
+        };
+    }
+}
+
+/// Walking left leaves the prints of walking right flipped
+fn footprints(location: Vec2, direction: Direction) {
+    let sprite = match direction {
+        Direction::Left | Direction::Right => vec2(14, 7),
+        Direction::Down => vec2(15, 7),
+        Direction::Up => vec2(16, 7),
+    };
+
+    asset(sprite, location)
+        .flip_x(direction == Direction::Left)
+        .draw();
+}
+
+#[must_use]
+struct Asset {
+    sprite: Vec2,
+    location: Vec2,
+    // In pixels, on top of `location`
+    nudge: (i32, i32),
+    opacity: f32,
+    flip_x: bool,
+}
+
+fn asset(sprite: Vec2, location: Vec2) -> Asset {
+    Asset::new(sprite, location)
+}
+
+fn animated(animation: &Animation, tick: usize, location: Vec2) -> Asset {
+    Asset::new(animation.frame(tick).sprite, location).animate(animation, tick)
+}
+
+impl Asset {
+    fn new(sprite: Vec2, location: Vec2) -> Asset {
+        Asset {
+            sprite,
+            location,
+            nudge: (0, 0),
+            opacity: 1.0,
+            flip_x: false,
+        }
+    }
+
+    #[allow(clippy::neg_multiply)]
+    fn draw(self) {
+        sprite!(
+            "assets",
+            x = (CELL_SIZE * self.location.x) as i32 + self.nudge.0,
+            y = (CELL_SIZE * self.location.y) as i32 + self.nudge.1,
+            w = CELL_SIZE,
+            h = CELL_SIZE,
+            sw = CELL_SIZE,
+            sh = CELL_SIZE,
+            sx = self.sprite.x * 16,
+            sy = self.sprite.y * 16,
+            opacity = self.opacity,
+            flip_x = self.flip_x
+        );
+    }
+
+    fn flip_x(self, flip_x: bool) -> Asset {
+        Asset { flip_x, ..self }
+    }
+
+    /// Moves the asset by some pixels, on top of wherever it's already been moved
+    fn nudge(self, (x, y): (i32, i32)) -> Asset {
+        Asset {
+            nudge: (self.nudge.0 + x, self.nudge.1 + y),
+            ..self
+        }
+    }
+
+    /// Swaps in whichever frame of `animation` is up `tick` ticks in
+    fn animate(self, animation: &Animation, tick: usize) -> Asset {
+        let frame = animation.frame(tick);
+        Asset {
+            sprite: frame.sprite,
+            ..self
+        }
+        .nudge(frame.nudge)
+    }
+}
+
+fn progress(location: Vec2, progress: f32) {
+    let rect_size = lerp(PROGRESS_BAR_SIZE, 0, progress);
+    rect!(
+        x = CELL_SIZE * location.x + (CELL_SIZE - PROGRESS_BAR_SIZE) / 2,
+        y = CELL_SIZE * location.y - CELL_SIZE / 4,
+        w = PROGRESS_BAR_SIZE,
+        h = 2,
+        color = 0x888888ff,
+    );
+    rect!(
+        x = CELL_SIZE * location.x + (CELL_SIZE - PROGRESS_BAR_SIZE) / 2,
+        y = CELL_SIZE * location.y - CELL_SIZE / 4,
+        w = rect_size,
+        h = 2,
+        color = 0x00ff00ff,
+    );
+}
+
+fn lerp(start: usize, end: usize, t: f32) -> usize {
+    ((start as f32) * (1.0 - t) + (end as f32) * t).round() as usize
+}
+
+/// How far along a cleaner is in walking from their previous cell, `None` once they've arrived
+fn walk_progress(cleaner: &Cleaner, elapsed: usize) -> Option<f32> {
+    let since = elapsed.saturating_sub(cleaner.moved_at);
+    (cleaner.previous_position != cleaner.position && since < FRAMES_BETWEEN_MOVES)
+        .then(|| since as f32 / FRAMES_BETWEEN_MOVES as f32)
+}
+
+/// Where a cleaner is drawn relative to their cell, in pixels, sliding from the previous one
+fn walk_offset(cleaner: &Cleaner, elapsed: usize) -> (f32, f32) {
+    let Some(t) = walk_progress(cleaner, elapsed) else {
+        return (0.0, 0.0);
+    };
+    let remaining = (1.0 - t) * CELL_SIZE as f32;
+    (
+        (cleaner.previous_position.x as f32 - cleaner.position.x as f32) * remaining,
+        (cleaner.previous_position.y as f32 - cleaner.position.y as f32) * remaining,
+    )
+}
+
+/// Where a cleaner is on screen, in pixels, mid-step included
+pub fn cleaner_location(cleaner: &Cleaner, elapsed: usize) -> (f32, f32) {
+    let (x, y) = walk_offset(cleaner, elapsed);
+    (
+        (CELL_SIZE * cleaner.position.x) as f32 + x,
+        (CELL_SIZE * cleaner.position.y) as f32 + y,
+    )
+}
+
+fn draw_cleaner(cleaner: &Cleaner, elapsed: usize) {
+    let (x, y) = walk_offset(cleaner, elapsed);
+    let (pose, tick) = match walk_progress(cleaner, elapsed) {
+        Some(_) => (Pose::Walk, elapsed - cleaner.moved_at),
+        None => (Pose::Idle, tick()),
+    };
+
+    animated(
+        animation::character(cleaner.character, pose, cleaner.facing),
+        tick,
+        cleaner.position,
+    )
+    .flip_x(matches!(cleaner.facing, Direction::Left))
+    .nudge((x.round() as i32, y.round() as i32))
+    .draw();
+}
+
+pub fn draw_level(state: &LevelState) {
+    state.grid.iter().enumerate().for_each(|(row_index, row)| {
+        row.iter().enumerate().for_each(|(column_index, cell)| {
+            let location = vec2(row_index, column_index);
+            asset(
+                match cell.background {
+                    TileBackground::Floor(sprite) => sprite,
+                    TileBackground::Wall(sprite) => sprite,
+                },
+                location,
+            )
+            .draw();
+
+            if cell.blood_level != BloodLevel::None {
+                cell.blood_level.draw(location);
+            }
+
+            if let Some(direction) = cell.footprints {
+                footprints(location, direction);
+            }
+
+            if let Furniture::Floor(sprite)
+            | Furniture::Wall(sprite)
+            | Furniture::Sink(sprite)
+            | Furniture::Doormat(sprite) = cell.furniture
+            {
+                asset(sprite, location).draw();
+            }
+
+            cell.item.draw(location, false, (0, 0));
+        })
+    });
+
+    // Cleaners go on top of the whole floor since mid-step they overlap the cell they came from
+    state
+        .cleaners
+        .iter()
+        .for_each(|cleaner| draw_cleaner(cleaner, state.elapsed));
+
+    state.grid.iter().enumerate().for_each(|(row_index, row)| {
+        row.iter().enumerate().for_each(|(column_index, cell)| {
+            if let Some(foreground) = cell.foreground {
+                asset(foreground, vec2(row_index, column_index)).draw();
+            }
+        })
+    });
+
+    // Held items float in front of whoever is holding them, on top of everything else
+    state
+        .cleaners
+        .iter()
+        .enumerate()
+        .for_each(|(index, cleaner)| {
+            if let Some(in_front) = state.in_front_of(index) {
+                let (x, y) = walk_offset(cleaner, state.elapsed);
+                let nudge = (x.round() as i32, y.round() as i32);
+                let flip = matches!(cleaner.facing, Direction::Left);
+
+                match cleaner
+                    .inventory
+                    .work_animation()
+                    .filter(|_| cleaner.worked_at == Some(state.elapsed))
+                {
+                    Some(animation) => animated(animation, tick(), in_front)
+                        .flip_x(flip)
+                        .nudge(nudge)
+                        .draw(),
+                    None => cleaner.inventory.draw(in_front, flip, nudge),
+                }
+            }
+        });
+}
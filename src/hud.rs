@@ -0,0 +1,77 @@
+//! Everything drawn in screen space on top of a level, so it stays put when the camera moves
+
+use turbo::prelude::*;
+
+use crate::format_time;
+use crate::level::{BloodLevel, Item, LevelState};
+
+const LINE_HEIGHT: usize = 10;
+const WHITE: u32 = 0xffffffff;
+const RED: u32 = 0xff4444ff;
+
+pub fn draw_hud(level_name: &str, state: &LevelState) {
+    let objectives = state.objectives();
+
+    // From the top of the stack down, the level summary ends up on the bottom line
+    let mut lines = state
+        .cleaners
+        .iter()
+        .map(|cleaner| {
+            let (boots, color) = match cleaner.blood_on_boots {
+                BloodLevel::None => ("CLEAN", WHITE),
+                BloodLevel::Tall(_) => ("TALL", 0xff8888ff),
+                BloodLevel::Grande(_) => ("GRANDE", RED),
+                BloodLevel::Venti(_) => ("VENTI", 0xff0000ff),
+            };
+            let holding = match cleaner.inventory {
+                Item::Sponge(_) if !cleaner.inventory.can_clean() => "SOAKED SPONGE".to_string(),
+                item => item.name().to_uppercase(),
+            };
+
+            (
+                format!(
+                    "{}: {holding}, {boots} BOOTS",
+                    cleaner.character.name().to_uppercase()
+                ),
+                color,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(arrival) = state.police_arrive_at {
+        lines.push((
+            format!(
+                "POLICE IN {}",
+                format_time(arrival.saturating_sub(state.elapsed))
+            ),
+            RED,
+        ));
+    }
+
+    lines.push(("START: REPLAY  HOLD START: OVERVIEW".to_string(), WHITE));
+
+    lines.push((
+        format!(
+            "{}  BLOOD {}  BODIES {}  BAGS {}",
+            level_name.to_uppercase(),
+            objectives.blood_tiles,
+            objectives.bodies,
+            objectives.body_bags
+        ),
+        WHITE,
+    ));
+
+    let [_, height] = canvas_size!();
+    let bottom = height as usize - 4;
+    let count = lines.len();
+    lines.iter().enumerate().for_each(|(index, (line, color))| {
+        text!(
+            line,
+            x = 8,
+            y = bottom - (count - index) * LINE_HEIGHT,
+            font = Font::M,
+            color = *color,
+            absolute = true
+        );
+    });
+}
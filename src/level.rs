@@ -0,0 +1,1041 @@
+use std::collections::{HashSet, VecDeque};
+use turbo::borsh::{self, *};
+
+use crate::camera::Camera;
+use crate::levels;
+
+pub const BODY_CHOPPING_TIME: isize = 100;
+pub const CLEANING_TIME: isize = 100;
+pub const TICKS_PER_SECOND: usize = 60;
+pub const POOLING_TIME: usize = 5 * TICKS_PER_SECOND;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Character {
+    TheCat,
+    TwoToes,
+    MachineGun,
+    OneEye,
+}
+
+impl Character {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Character::TheCat => "The Cat",
+            Character::TwoToes => "Two Toes",
+            Character::MachineGun => "Machine Gun",
+            Character::OneEye => "One Eye",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Character::TheCat => Character::TwoToes,
+            Character::TwoToes => Character::MachineGun,
+            Character::MachineGun => Character::OneEye,
+            Character::OneEye => Character::TheCat,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+pub struct Vec2 {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Clone, Copy)]
+pub struct IVec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+pub const fn vec2(x: usize, y: usize) -> Vec2 {
+    Vec2 { x, y }
+}
+
+pub const fn ivec2(x: isize, y: isize) -> IVec2 {
+    IVec2 { x, y }
+}
+
+impl Vec2 {
+    /// Moves by `offset`, or `None` if that would go past the top or left edge
+    pub fn checked_add(self, offset: IVec2) -> Option<Vec2> {
+        Some(Vec2 {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum TileBackground {
+    Floor(Vec2),
+    Wall(Vec2),
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Furniture {
+    None,
+    Floor(Vec2),
+    Wall(Vec2),
+    // Blocks the way like a wall, and rinses out sponges
+    Sink(Vec2),
+    // Walked over like the floor, wiping some of the blood off the boots of whoever steps on it
+    Doormat(Vec2),
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum BodyLevel {
+    // CHOP THIS
+    Start,
+    One,
+    Two,
+    Three,
+
+    // BAG THIS
+    Four,
+    Five,
+    Six,
+    Seven,
+}
+
+#[derive(PartialEq)]
+pub enum BodyAction {
+    Chop,
+    Bag,
+}
+
+impl BodyLevel {
+    pub fn lower(self) -> Option<Self> {
+        match self {
+            BodyLevel::Start => Some(BodyLevel::One),
+            BodyLevel::One => Some(BodyLevel::Two),
+            BodyLevel::Two => Some(BodyLevel::Three),
+            BodyLevel::Three => Some(BodyLevel::Four),
+            BodyLevel::Four => Some(BodyLevel::Five),
+            BodyLevel::Five => Some(BodyLevel::Six),
+            BodyLevel::Six => Some(BodyLevel::Seven),
+            BodyLevel::Seven => None,
+        }
+    }
+
+    pub fn action(&self) -> BodyAction {
+        match self {
+            BodyLevel::Start | BodyLevel::One | BodyLevel::Two | BodyLevel::Three => {
+                BodyAction::Chop
+            }
+            BodyLevel::Four | BodyLevel::Five | BodyLevel::Six | BodyLevel::Seven => {
+                BodyAction::Bag
+            }
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Item {
+    None,
+    Body(BodyLevel, isize),
+    Knife,
+    // How many levels of blood each has cleaned, see `CleaningTool::uses`
+    Sponge(usize),
+    Bleach(usize),
+    Bag,
+    BagRoll,
+    BodyBag,
+}
+
+/// How a cleaning item deals with blood
+pub struct CleaningTool {
+    /// Taken off the cleaning progress of the blood in front every tick
+    pub strength: isize,
+    /// Levels of blood it can clean before it's saturated or used up
+    pub uses: usize,
+    /// Used up items disappear, saturated ones need rinsing at a sink
+    pub consumable: bool,
+}
+
+pub const SPONGE: CleaningTool = CleaningTool {
+    strength: 2,
+    uses: 6,
+    consumable: false,
+};
+
+pub const BLEACH: CleaningTool = CleaningTool {
+    strength: CLEANING_TIME,
+    uses: 4,
+    consumable: true,
+};
+
+impl Item {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Item::None => "Nothing",
+            Item::Body(_, _) => "Body",
+            Item::Knife => "Knife",
+            Item::Sponge(_) => "Sponge",
+            Item::Bleach(_) => "Bleach",
+            Item::Bag => "Bag",
+            Item::BagRoll => "Bag roll",
+            Item::BodyBag => "Body bag",
+        }
+    }
+
+    pub fn collidable(&self) -> bool {
+        match self {
+            Item::None
+            | Item::Body(_, _)
+            | Item::Knife
+            | Item::Sponge(_)
+            | Item::Bleach(_)
+            | Item::Bag
+            | Item::BagRoll => false,
+            Item::BodyBag => true,
+        }
+    }
+
+    pub fn cleaning_tool(&self) -> Option<&'static CleaningTool> {
+        match self {
+            Item::None
+            | Item::Body(_, _)
+            | Item::Knife
+            | Item::Bag
+            | Item::BagRoll
+            | Item::BodyBag => None,
+            Item::Sponge(_) => Some(&SPONGE),
+            Item::Bleach(_) => Some(&BLEACH),
+        }
+    }
+
+    /// A cleaning item that isn't saturated
+    pub fn can_clean(&self) -> bool {
+        match self {
+            Item::Sponge(used) | Item::Bleach(used) => {
+                self.cleaning_tool().is_some_and(|tool| *used < tool.uses)
+            }
+            _ => false,
+        }
+    }
+
+    /// Counts one more level of blood cleaned, consumables disappear once they run out
+    fn wear(&mut self) {
+        let Some(tool) = self.cleaning_tool() else {
+            return;
+        };
+        let (Item::Sponge(used) | Item::Bleach(used)) = self else {
+            return;
+        };
+
+        *used += 1;
+        if tool.consumable && *used >= tool.uses {
+            *self = Item::None;
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum BloodLevel {
+    None,
+    Tall(isize),
+    Grande(isize),
+    Venti(isize),
+}
+
+impl BloodLevel {
+    /// Returns whether incrementing succeeded or not
+    pub fn increment(&mut self) -> bool {
+        match self {
+            BloodLevel::None => {
+                *self = BloodLevel::Tall(CLEANING_TIME);
+                true
+            }
+            BloodLevel::Tall(_) => {
+                *self = BloodLevel::Grande(CLEANING_TIME);
+                true
+            }
+            BloodLevel::Grande(_) => {
+                *self = BloodLevel::Venti(CLEANING_TIME);
+                true
+            }
+            BloodLevel::Venti(_) => false,
+        }
+    }
+
+    /// Returns whether decrementing succeeded or not
+    pub fn decrement(&mut self) -> bool {
+        match self {
+            BloodLevel::None => false,
+            BloodLevel::Tall(_) => {
+                *self = BloodLevel::None;
+                true
+            }
+            BloodLevel::Grande(_) => {
+                *self = BloodLevel::Tall(CLEANING_TIME);
+                true
+            }
+            BloodLevel::Venti(_) => {
+                *self = BloodLevel::Grande(CLEANING_TIME);
+                true
+            }
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct Tile {
+    pub background: TileBackground,
+    pub item: Item,
+    pub furniture: Furniture,
+    pub foreground: Option<Vec2>,
+    pub player: bool,
+    pub drop_point: bool,
+    pub blood_level: BloodLevel,
+    // The way the last cleaner with bloody boots was walking when they stepped here
+    pub footprints: Option<Direction>,
+}
+
+impl Tile {
+    pub fn walkable(&self) -> bool {
+        if let TileBackground::Wall(_) = self.background {
+            return false;
+        }
+        if let Furniture::Wall(_) | Furniture::Sink(_) = self.furniture {
+            return false;
+        }
+
+        !self.item.collidable()
+    }
+}
+
+/// Where the first cleaner starts out in a level
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct Spawn {
+    pub position: Vec2,
+    pub facing: Direction,
+    // Set when the level insists on who plays it, whoever was picked otherwise
+    pub character: Option<Character>,
+}
+
+/// Seconds to beat for each star rating, set per level in Cleaners.ldtk
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct Par {
+    pub three_stars: usize,
+    pub two_stars: usize,
+}
+
+impl Par {
+    /// Finishing a level at all is worth one star
+    pub fn stars(&self, ticks: usize) -> u8 {
+        if ticks <= self.three_stars * TICKS_PER_SECOND {
+            3
+        } else if ticks <= self.two_stars * TICKS_PER_SECOND {
+            2
+        } else {
+            1
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct Grid(pub Vec<Vec<Tile>>);
+
+impl Grid {
+    pub fn load(level_index: usize) -> (Self, Spawn) {
+        let level = &levels::all()[level_index];
+
+        (Grid(level.columns.clone()), level.spawn)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Vec<Tile>> {
+        self.0.iter()
+    }
+
+    pub fn width(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The height of the tallest column, levels aren't guaranteed to be rectangular
+    pub fn height(&self) -> usize {
+        self.0.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn get(&self, position: Vec2) -> Option<&Tile> {
+        self.0.get(position.x)?.get(position.y)
+    }
+
+    pub fn get_mut(&mut self, position: Vec2) -> Option<&mut Tile> {
+        self.0.get_mut(position.x)?.get_mut(position.y)
+    }
+
+    /// The position `offset` away from `position`, or `None` if it falls off the grid
+    pub fn offset(&self, position: Vec2, offset: IVec2) -> Option<Vec2> {
+        let moved = position.checked_add(offset)?;
+        self.get(moved).map(|_| moved)
+    }
+}
+
+impl std::ops::Index<Vec2> for Grid {
+    type Output = Tile;
+
+    fn index(&self, index: Vec2) -> &Self::Output {
+        &self.0[index.x][index.y]
+    }
+}
+
+impl std::ops::IndexMut<Vec2> for Grid {
+    fn index_mut(&mut self, index: Vec2) -> &mut Self::Output {
+        &mut self.0[index.x][index.y]
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
+pub struct Stats {
+    pub ticks: usize,
+    pub body_bags_delivered: usize,
+    pub blood_tiles_cleaned: usize,
+}
+
+impl std::ops::Add for Stats {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Stats {
+            ticks: self.ticks + rhs.ticks,
+            body_bags_delivered: self.body_bags_delivered + rhs.body_bags_delivered,
+            blood_tiles_cleaned: self.blood_tiles_cleaned + rhs.blood_tiles_cleaned,
+        }
+    }
+}
+
+/// One of the players, each with their own hands, boots and gamepad
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct Cleaner {
+    // Gameplay:
+    pub position: Vec2,
+    pub blood_on_boots: BloodLevel,
+    pub facing: Direction,
+    pub inventory: Item,
+
+    // Restrictions:
+    pub disable_move_until: usize,
+    pub last_frame_directions: HashSet<Direction>,
+
+    // Fun
+    pub character: Character,
+
+    // Presentation:
+    // Where the cleaner last moved from, and the `elapsed` tick they did it on
+    pub previous_position: Vec2,
+    pub moved_at: usize,
+    // The last `elapsed` tick they chopped, bagged or cleaned on
+    pub worked_at: Option<usize>,
+}
+
+impl Cleaner {
+    fn new(position: Vec2, character: Character) -> Self {
+        Cleaner {
+            position,
+            blood_on_boots: BloodLevel::None,
+            facing: Direction::Down,
+            inventory: Item::None,
+            disable_move_until: 0,
+            last_frame_directions: HashSet::new(),
+            character,
+            previous_position: position,
+            moved_at: 0,
+            worked_at: None,
+        }
+    }
+}
+
+/// What's left to clean up before the level is done
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Objectives {
+    pub blood_tiles: usize,
+    pub bodies: usize,
+    // Including the ones still in someone's hands
+    pub body_bags: usize,
+}
+
+/// Enough to take a level back to an earlier point: the cleaners, stats and objectives as they
+/// were then, and every tile changed since as it was before the change
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct Snapshot {
+    cleaners: Vec<Cleaner>,
+    stats: Stats,
+    objectives: Objectives,
+    tiles: Vec<(Vec2, Tile)>,
+}
+
+impl Snapshot {
+    /// Keeps track of tiles as they were before a change, a tile already kept was changed
+    /// earlier on and stays as it was then
+    pub fn record(&mut self, tiles: impl IntoIterator<Item = (Vec2, Tile)>) {
+        tiles.into_iter().for_each(|(position, tile)| {
+            if !self.tiles.iter().any(|(kept, _)| *kept == position) {
+                self.tiles.push((position, tile));
+            }
+        });
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct LevelState {
+    pub grid: Grid,
+    // The first cleaner is always there, a second one joins for co-op
+    pub cleaners: Vec<Cleaner>,
+
+    // Stats:
+    pub stats: Stats,
+    // Ticks since the level was last (re)started, unlike `stats.ticks` which carries over
+    pub elapsed: usize,
+    // In `elapsed` ticks, only set when playing against the clock
+    pub police_arrive_at: Option<usize>,
+    // Whether venti blood seeps into the tiles around it over time
+    pub pooling: bool,
+    // Kept up to date by every rule that changes the grid, see `recount_objectives`
+    objectives: Objectives,
+
+    // Undo:
+    // The tiles the last action or spreading blood touched, as they were before it
+    touched: Vec<(Vec2, Tile)>,
+
+    // Presentation:
+    pub camera: Camera,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, PartialOrd, Eq, Hash, Debug, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl From<Direction> for IVec2 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => ivec2(0, -1),
+            Direction::Down => ivec2(0, 1),
+            Direction::Left => ivec2(-1, 0),
+            Direction::Right => ivec2(1, 0),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LevelFinished {
+    Yes,
+    No,
+}
+
+/// A single discrete thing the player can do to the level, independent of input handling
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Action {
+    Move(Direction),
+    Interact,
+    Drop,
+}
+
+impl LevelState {
+    pub fn move_cleaner(&mut self, cleaner: usize, direction: Direction) {
+        // Only the tile stepped off and the one stepped onto can change
+        let standing_on = self.cleaners[cleaner].position;
+        self.touch(standing_on);
+        if let Some(ahead) = self.grid.offset(standing_on, IVec2::from(direction)) {
+            self.touch(ahead);
+        }
+
+        let Cleaner {
+            position,
+            facing,
+            blood_on_boots,
+            previous_position: from,
+            moved_at,
+            ..
+        } = &mut self.cleaners[cleaner];
+        let previous_position = *position;
+        // Turning on the spot shouldn't replay the last step
+        *from = previous_position;
+        *facing = direction;
+        let Some(new_position) = self.grid.offset(*position, IVec2::from(direction)) else {
+            return;
+        };
+        // Cleaners bump into each other just like they do into walls
+        if !self.grid[new_position].walkable() || self.grid[new_position].player {
+            return;
+        }
+        *position = new_position;
+        *moved_at = self.elapsed;
+        let was_clean = self.grid[new_position].blood_level == BloodLevel::None;
+
+        if let Item::Body(_, _) = self.grid[new_position].item {
+            self.grid[new_position].blood_level = BloodLevel::Venti(CLEANING_TIME);
+            *blood_on_boots = BloodLevel::Venti(CLEANING_TIME);
+        }
+
+        if let Furniture::Doormat(_) = self.grid[new_position].furniture {
+            blood_on_boots.decrement();
+        } else if *blood_on_boots != BloodLevel::None {
+            self.grid[new_position].footprints = Some(direction);
+
+            let some_blood_drained = self.grid[new_position].blood_level.increment();
+            if some_blood_drained {
+                blood_on_boots.decrement();
+            }
+        } else if self.grid[new_position].blood_level != BloodLevel::None {
+            // Clean boots pick up a little of whatever they step in
+            blood_on_boots.increment();
+        }
+
+        if was_clean && self.grid[new_position].blood_level != BloodLevel::None {
+            self.objectives.blood_tiles += 1;
+        }
+
+        self.grid[previous_position].player = false;
+        self.grid[new_position].player = true
+    }
+
+    /// The tile a cleaner is facing, `None` when they're facing the edge of the level
+    pub fn in_front_of(&self, cleaner: usize) -> Option<Vec2> {
+        let cleaner = &self.cleaners[cleaner];
+        self.grid
+            .offset(cleaner.position, IVec2::from(cleaner.facing))
+    }
+
+    /// Whether the countdown ran out before the level was cleaned up
+    pub fn police_arrived(&self) -> bool {
+        self.police_arrive_at
+            .is_some_and(|arrival| self.elapsed >= arrival)
+    }
+
+    /// Every `POOLING_TIME` ticks each venti puddle spreads into one clean tile next to it
+    pub fn spread_blood(&mut self) {
+        self.touched.clear();
+
+        if !self.pooling || !self.elapsed.is_multiple_of(POOLING_TIME) {
+            return;
+        }
+
+        // Collected up front so that fresh blood doesn't spread again on the same tick
+        let puddles = self
+            .grid
+            .iter()
+            .enumerate()
+            .flat_map(|(x, column)| {
+                column
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, tile)| matches!(tile.blood_level, BloodLevel::Venti(_)))
+                    .map(move |(y, _)| vec2(x, y))
+            })
+            .collect::<Vec<_>>();
+
+        puddles.into_iter().for_each(|puddle| {
+            let spot = [
+                Direction::Up,
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+            ]
+            .into_iter()
+            .filter_map(|direction| self.grid.offset(puddle, IVec2::from(direction)))
+            .find(|&spot| {
+                let tile = &self.grid[spot];
+                tile.walkable() && tile.blood_level == BloodLevel::None
+            });
+
+            if let Some(spot) = spot {
+                self.touch(spot);
+                self.grid[spot].blood_level.increment();
+                self.objectives.blood_tiles += 1;
+            }
+        });
+
+        self.check_objectives();
+    }
+
+    pub fn objectives(&self) -> Objectives {
+        self.objectives
+    }
+
+    /// Counts everything left to clean up from scratch. Only needed after editing the grid or
+    /// someone's hands directly, the rules keep the count up to date themselves
+    pub fn recount_objectives(&mut self) {
+        self.objectives = self.scan_objectives();
+    }
+
+    fn scan_objectives(&self) -> Objectives {
+        let held = self
+            .cleaners
+            .iter()
+            .filter(|cleaner| cleaner.inventory == Item::BodyBag)
+            .count();
+        let start = Objectives {
+            body_bags: held,
+            ..Objectives::default()
+        };
+
+        self.grid
+            .iter()
+            .flatten()
+            .fold(start, |mut objectives, tile| {
+                if tile.blood_level != BloodLevel::None {
+                    objectives.blood_tiles += 1;
+                }
+                match tile.item {
+                    Item::Body(_, _) => objectives.bodies += 1,
+                    Item::BodyBag => objectives.body_bags += 1,
+                    _ => {}
+                }
+
+                objectives
+            })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.objectives == Objectives::default()
+    }
+
+    pub fn interact(&mut self, cleaner: usize) -> LevelFinished {
+        let Some(in_front_of_player) = self.in_front_of(cleaner) else {
+            return LevelFinished::No;
+        };
+        self.touch(in_front_of_player);
+        if let (Furniture::Sink(_), Item::Sponge(used)) = (
+            self.grid[in_front_of_player].furniture,
+            &mut self.cleaners[cleaner].inventory,
+        ) {
+            *used = 0;
+            return LevelFinished::No;
+        }
+
+        if let Some(tool) = self.cleaners[cleaner]
+            .inventory
+            .cleaning_tool()
+            .filter(|_| self.cleaners[cleaner].inventory.can_clean())
+        {
+            match &mut self.grid[in_front_of_player].blood_level {
+                BloodLevel::None => {}
+                BloodLevel::Tall(progress)
+                | BloodLevel::Grande(progress)
+                | BloodLevel::Venti(progress) => {
+                    *progress -= tool.strength;
+                    self.cleaners[cleaner].worked_at = Some(self.elapsed);
+
+                    if *progress <= 0 {
+                        self.grid[in_front_of_player].blood_level.decrement();
+                        self.cleaners[cleaner].inventory.wear();
+
+                        if self.grid[in_front_of_player].blood_level == BloodLevel::None {
+                            self.grid[in_front_of_player].footprints = None;
+                            self.stats.blood_tiles_cleaned += 1;
+                            self.objectives.blood_tiles -= 1;
+
+                            if self.is_finished() {
+                                return LevelFinished::Yes;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let elapsed = self.elapsed;
+        let Cleaner {
+            inventory,
+            worked_at,
+            ..
+        } = &mut self.cleaners[cleaner];
+
+        match &mut self.grid[in_front_of_player].item {
+            Item::None => LevelFinished::No,
+            Item::Body(level, progress) => {
+                if level.action() == BodyAction::Bag {
+                    if *inventory != Item::Bag {
+                        return LevelFinished::No;
+                    }
+
+                    *progress -= 1;
+                    *worked_at = Some(elapsed);
+
+                    if *progress <= 0 {
+                        *inventory = Item::None;
+
+                        match level.lower() {
+                            Some(new_level) => {
+                                self.grid[in_front_of_player].item =
+                                    Item::Body(new_level, BODY_CHOPPING_TIME);
+                            }
+                            None => {
+                                self.grid[in_front_of_player].item = Item::None;
+                                self.objectives.bodies -= 1;
+                            }
+                        }
+
+                        self.objectives.body_bags += 1;
+                        match self.free_spot_around(in_front_of_player) {
+                            Some(spot) => {
+                                self.touch(spot);
+                                self.grid[spot].item = Item::BodyBag;
+                            }
+                            // Nowhere to put it down, so the cleaner keeps holding it
+                            None => self.cleaners[cleaner].inventory = Item::BodyBag,
+                        }
+                    }
+
+                    return LevelFinished::No;
+                }
+
+                if *inventory != Item::Knife {
+                    return LevelFinished::No;
+                }
+
+                *progress -= 1;
+                *worked_at = Some(elapsed);
+
+                if *progress <= 0 {
+                    let Some(new_level) = level.lower() else {
+                        self.grid[in_front_of_player].item = Item::None;
+                        self.objectives.bodies -= 1;
+                        return LevelFinished::No;
+                    };
+                    self.grid[in_front_of_player].item = Item::Body(new_level, BODY_CHOPPING_TIME);
+                }
+
+                LevelFinished::No
+            }
+            item
+            @ (Item::Knife | Item::Sponge(_) | Item::Bleach(_) | Item::Bag | Item::BodyBag) => {
+                if *inventory != Item::None {
+                    return LevelFinished::No;
+                }
+
+                *inventory = *item;
+                self.grid[in_front_of_player].item = Item::None;
+
+                LevelFinished::No
+            }
+            Item::BagRoll => {
+                if *inventory != Item::None {
+                    return LevelFinished::No;
+                }
+
+                *inventory = Item::Bag;
+
+                LevelFinished::No
+            }
+        }
+    }
+
+    /// The closest free tile a cleaner could walk to from a position, so nothing put there ends
+    /// up walled off. The position itself comes first, for a body that was just bagged away.
+    ///
+    /// Rather than trying all eight neighbours and then wider rings, the search spreads out the
+    /// four ways cleaners walk. A diagonal neighbour can be cut off by the walls on either side
+    /// of it, so looking at the ring first would put bags where nobody can reach them
+    fn free_spot_around(&self, around: Vec2) -> Option<Vec2> {
+        let free = |tile: &Tile| tile.walkable() && tile.item == Item::None && !tile.player;
+        if free(&self.grid[around]) {
+            return Some(around);
+        }
+
+        // Starting below and going clockwise
+        let directions = [
+            Direction::Down,
+            Direction::Right,
+            Direction::Up,
+            Direction::Left,
+        ];
+        let mut seen = HashSet::from([around]);
+        let mut queue = VecDeque::from([around]);
+
+        while let Some(position) = queue.pop_front() {
+            for direction in directions {
+                let Some(spot) = self.grid.offset(position, IVec2::from(direction)) else {
+                    continue;
+                };
+                // Cleaners get out of the way, so they don't cut anything off
+                if !self.grid[spot].walkable() || !seen.insert(spot) {
+                    continue;
+                }
+                if free(&self.grid[spot]) {
+                    return Some(spot);
+                }
+
+                queue.push_back(spot);
+            }
+        }
+
+        None
+    }
+
+    pub fn drop(&mut self, cleaner: usize) -> LevelFinished {
+        let Some(in_front_of_player) = self.in_front_of(cleaner) else {
+            return LevelFinished::No;
+        };
+        self.touch(in_front_of_player);
+        let inventory = self.cleaners[cleaner].inventory;
+
+        if self.grid[in_front_of_player].drop_point && inventory == Item::BodyBag {
+            self.cleaners[cleaner].inventory = Item::None;
+            self.stats.body_bags_delivered += 1;
+            self.objectives.body_bags -= 1;
+            return match self.is_finished() {
+                true => LevelFinished::Yes,
+                false => LevelFinished::No,
+            };
+        }
+
+        // Dropping towards another cleaner hands them the item, if their hands are free
+        if let Some(other) = self
+            .cleaners
+            .iter()
+            .position(|other| other.position == in_front_of_player)
+        {
+            if self.cleaners[other].inventory == Item::None {
+                self.cleaners[other].inventory = inventory;
+                self.cleaners[cleaner].inventory = Item::None;
+            }
+
+            return LevelFinished::No;
+        }
+
+        if self.grid[in_front_of_player].item != Item::None {
+            return LevelFinished::No;
+        }
+
+        self.grid[in_front_of_player].item = inventory;
+        self.cleaners[cleaner].inventory = Item::None;
+
+        LevelFinished::No
+    }
+
+    pub fn apply(&mut self, cleaner: usize, action: Action) -> LevelFinished {
+        self.touched.clear();
+
+        let finished = match action {
+            Action::Move(direction) => {
+                self.move_cleaner(cleaner, direction);
+                LevelFinished::No
+            }
+            Action::Interact => self.interact(cleaner),
+            Action::Drop => self.drop(cleaner),
+        };
+
+        self.check_objectives();
+        finished
+    }
+
+    /// Catches a rule that forgot to keep the objectives up to date
+    fn check_objectives(&self) {
+        debug_assert_eq!(
+            self.objectives,
+            self.scan_objectives(),
+            "Objectives drifted from what's actually left in the level"
+        );
+    }
+}
+
+impl LevelState {
+    pub fn new((grid, spawn): (Grid, Spawn), character: Character) -> Self {
+        let mut cleaner = Cleaner::new(spawn.position, spawn.character.unwrap_or(character));
+        cleaner.facing = spawn.facing;
+
+        let mut state = LevelState {
+            grid,
+            cleaners: vec![cleaner],
+            stats: Stats::default(),
+            elapsed: 0,
+            police_arrive_at: None,
+            pooling: false,
+            objectives: Objectives::default(),
+            touched: Vec::new(),
+            camera: Camera::default(),
+        };
+        state.recount_objectives();
+
+        state
+    }
+
+    pub fn characters(&self) -> Vec<Character> {
+        self.cleaners
+            .iter()
+            .map(|cleaner| cleaner.character)
+            .collect()
+    }
+
+    /// Loads a level with a cleaner for each character, as long as there's room for them
+    pub fn spawn(level_index: usize, characters: &[Character]) -> Self {
+        let mut state = LevelState::new(Grid::load(level_index), characters[0]);
+        characters[1..].iter().for_each(|&character| {
+            state.join(character);
+        });
+
+        state
+    }
+
+    /// Brings in another cleaner on the closest free tile to the first one, returns whether
+    /// there was room for them
+    pub fn join(&mut self, character: Character) -> bool {
+        let Some(position) = self.free_spot_around(self.cleaners[0].position) else {
+            return false;
+        };
+
+        self.grid[position].player = true;
+        self.cleaners.push(Cleaner::new(position, character));
+
+        true
+    }
+
+    /// Remembers a tile as it is before the current action or spreading blood changes it
+    fn touch(&mut self, position: Vec2) {
+        if !self.touched.iter().any(|(touched, _)| *touched == position) {
+            self.touched.push((position, self.grid[position]));
+        }
+    }
+
+    /// The tiles the last action or spreading blood changed, as they were before it
+    pub fn changed_tiles(&self) -> impl Iterator<Item = (Vec2, Tile)> + '_ {
+        self.touched
+            .iter()
+            .copied()
+            .filter(|&(position, tile)| self.grid[position] != tile)
+    }
+
+    /// A point to come back to with `rewind`, it starts out without any tiles and has every
+    /// change from here on `record`ed into it
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            cleaners: self.cleaners.clone(),
+            stats: self.stats,
+            objectives: self.objectives,
+            tiles: Vec::new(),
+        }
+    }
+
+    /// Goes back to an earlier snapshot while keeping the clock and the chosen characters
+    pub fn rewind(&mut self, snapshot: Snapshot) {
+        self.cleaners
+            .iter_mut()
+            .zip(snapshot.cleaners)
+            .for_each(|(cleaner, snapshot)| {
+                *cleaner = Cleaner {
+                    disable_move_until: cleaner.disable_move_until,
+                    last_frame_directions: std::mem::take(&mut cleaner.last_frame_directions),
+                    character: cleaner.character,
+                    ..snapshot
+                };
+            });
+        snapshot
+            .tiles
+            .into_iter()
+            .for_each(|(position, tile)| self.grid[position] = tile);
+
+        self.stats = Stats {
+            ticks: self.stats.ticks,
+            ..snapshot.stats
+        };
+        self.objectives = snapshot.objectives;
+        self.touched.clear();
+    }
+}
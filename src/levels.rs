@@ -0,0 +1,153 @@
+//! The levels map-compiler bakes out of Cleaners.ldtk into `levels.bin`, and the format both sides
+//! agree on. The compiler writes the file with [`encode`] and the game reads it with [`decode`], so
+//! they share this module and can't disagree on the layout. [`FORMAT_VERSION`] catches a file
+//! compiled before the layout changed.
+
+use std::fmt;
+use std::sync::OnceLock;
+use turbo::borsh::{self, *};
+
+use crate::level::{Par, Spawn, Tile};
+
+/// Bumped whenever the stored layout changes, including the layout of `Tile`
+pub const FORMAT_VERSION: u16 = 1;
+const MAGIC: [u8; 4] = *b"CLNR";
+
+const LEVELS: &[u8] = include_bytes!("levels.bin");
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct LevelDefinition {
+    pub name: String,
+    pub par: Par,
+    // Indexed `[x][y]` like `Grid`
+    pub columns: Vec<Vec<Tile>>,
+    pub spawn: Spawn,
+}
+
+/// What's actually stored for a level, the tiles column after column with repeats collapsed
+#[derive(BorshSerialize, BorshDeserialize)]
+struct StoredLevel {
+    name: String,
+    par: Par,
+    spawn: Spawn,
+    heights: Vec<u32>,
+    runs: Vec<Run>,
+}
+
+/// `count` copies of the same tile in a row
+#[derive(BorshSerialize, BorshDeserialize)]
+struct Run {
+    count: u32,
+    tile: Tile,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct Header {
+    magic: [u8; 4],
+    version: u16,
+}
+
+#[derive(Debug)]
+pub enum FormatError {
+    NotALevelFile,
+    Version { found: u16 },
+    Corrupt(std::io::Error),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::NotALevelFile => write!(f, "not a compiled level file"),
+            FormatError::Version { found } => write!(
+                f,
+                "levels are in format version {found} but the game reads version \
+                 {FORMAT_VERSION}, run map-compiler again"
+            ),
+            FormatError::Corrupt(error) => write!(f, "levels don't match the format: {error}"),
+        }
+    }
+}
+
+impl From<&LevelDefinition> for StoredLevel {
+    fn from(level: &LevelDefinition) -> Self {
+        let mut runs: Vec<Run> = Vec::new();
+        level
+            .columns
+            .iter()
+            .flatten()
+            .for_each(|&tile| match runs.last_mut() {
+                Some(run) if run.tile == tile => run.count += 1,
+                _ => runs.push(Run { count: 1, tile }),
+            });
+
+        StoredLevel {
+            name: level.name.clone(),
+            par: level.par,
+            spawn: level.spawn,
+            heights: level
+                .columns
+                .iter()
+                .map(|column| column.len() as u32)
+                .collect(),
+            runs,
+        }
+    }
+}
+
+impl From<StoredLevel> for LevelDefinition {
+    fn from(level: StoredLevel) -> Self {
+        let mut tiles = level
+            .runs
+            .iter()
+            .flat_map(|run| std::iter::repeat_n(run.tile, run.count as usize));
+
+        LevelDefinition {
+            name: level.name,
+            par: level.par,
+            columns: level
+                .heights
+                .iter()
+                .map(|&height| tiles.by_ref().take(height as usize).collect())
+                .collect(),
+            spawn: level.spawn,
+        }
+    }
+}
+
+pub fn encode(levels: &[LevelDefinition]) -> Vec<u8> {
+    let header = Header {
+        magic: MAGIC,
+        version: FORMAT_VERSION,
+    };
+    let levels = levels.iter().map(StoredLevel::from).collect::<Vec<_>>();
+
+    let mut bytes = header.try_to_vec().expect("Writing to a Vec can't fail");
+    levels
+        .serialize(&mut bytes)
+        .expect("Writing to a Vec can't fail");
+
+    bytes
+}
+
+pub fn decode(mut bytes: &[u8]) -> Result<Vec<LevelDefinition>, FormatError> {
+    let header = Header::deserialize(&mut bytes).map_err(|_| FormatError::NotALevelFile)?;
+    if header.magic != MAGIC {
+        return Err(FormatError::NotALevelFile);
+    }
+    if header.version != FORMAT_VERSION {
+        return Err(FormatError::Version {
+            found: header.version,
+        });
+    }
+
+    let levels = Vec::<StoredLevel>::try_from_slice(bytes).map_err(FormatError::Corrupt)?;
+
+    Ok(levels.into_iter().map(LevelDefinition::from).collect())
+}
+
+/// Every level in the game, in order
+pub fn all() -> &'static [LevelDefinition] {
+    static DECODED: OnceLock<Vec<LevelDefinition>> = OnceLock::new();
+
+    DECODED.get_or_init(|| decode(LEVELS).unwrap_or_else(|error| panic!("levels.bin: {error}")))
+}
@@ -1,830 +1,802 @@
 #![allow(clippy::missing_safety_doc)]
 
-mod tiles;
-
-use std::collections::HashSet;
+pub mod animation;
+pub mod camera;
+mod draw;
+mod hud;
+pub mod level;
+pub mod levels;
+mod records;
+pub mod replay;
+pub mod sim;
+
+use level::{
+    Action, Character, Cleaner, Direction, LevelFinished, LevelState, Snapshot, Stats,
+    TICKS_PER_SECOND,
+};
+use records::Records;
+use replay::{FrameInput, InputLog, Replay, PLAYERS};
+use std::collections::{HashSet, VecDeque};
 use turbo::borsh::{self, *};
 use turbo::prelude::*;
 
-const CELL_SIZE: usize = 16;
 const FRAMES_BETWEEN_MOVES: usize = 16;
-const BODY_CHOPPING_TIME: isize = 100;
-const CLEANING_TIME: isize = 100;
-const PROGRESS_BAR_SIZE: usize = CELL_SIZE - 4;
-
-struct CharacterSpriteLocations {
-    down: Vec2,
-    right: Vec2,
-    up: Vec2,
-}
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum Character {
-    TheCat,
-    TwoToes,
-    MachineGun,
-    OneEye,
-}
-
-const THE_CAT: CharacterSpriteLocations = CharacterSpriteLocations {
-    down: vec2(4, 0),
-    right: vec2(4, 5),
-    up: vec2(5, 5),
-};
-const TWO_TOES: CharacterSpriteLocations = CharacterSpriteLocations {
-    down: vec2(5, 0),
-    right: vec2(6, 5),
-    up: vec2(7, 5),
-};
-const MACHINE_GUN: CharacterSpriteLocations = CharacterSpriteLocations {
-    down: vec2(6, 0),
-    right: vec2(8, 5),
-    up: vec2(9, 5),
-};
-const ONE_EYE: CharacterSpriteLocations = CharacterSpriteLocations {
-    down: vec2(7, 0),
-    right: vec2(10, 5),
-    up: vec2(11, 5),
-};
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
-struct Vec2 {
-    x: usize,
-    y: usize,
-}
-
-#[derive(Clone, Copy)]
-struct IVec2 {
-    x: isize,
-    y: isize,
-}
-
-impl IVec2 {
-    fn clockwise(self) -> Self {
-        match self {
-            IVec2 { x, y } if x == 0 && y == 1 => IVec2 { x: 1, y: 1 },
-            IVec2 { x, y } if x == 1 && y == 1 => IVec2 { x: 1, y: 0 },
-            IVec2 { x, y } if x == 1 && y == 0 => IVec2 { x: 1, y: -1 },
-            _ => panic!("No more clockwise please"),
+const HISTORY_SIZE: usize = 16;
+/// How long start has to be held before the camera pulls back to the whole level, any shorter
+/// and letting go of it plays the replay instead
+const HOLD_FOR_OVERVIEW: usize = 12;
+
+/// Snapshots of the level taken before every move, pickup and drop, newest last. Each one only
+/// keeps the tiles changed between it and the next, so undoing goes back one at a time
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Default)]
+struct History(VecDeque<Snapshot>);
+
+impl History {
+    fn push(&mut self, snapshot: Snapshot) {
+        if self.0.len() == HISTORY_SIZE {
+            self.0.pop_front();
         }
-    }
-}
-
-const fn vec2(x: usize, y: usize) -> Vec2 {
-    Vec2 { x, y }
-}
-
-const fn ivec2(x: isize, y: isize) -> IVec2 {
-    IVec2 { x, y }
-}
 
-impl std::ops::AddAssign<IVec2> for Vec2 {
-    fn add_assign(&mut self, rhs: IVec2) {
-        self.x = ((self.x as isize) + rhs.x) as usize;
-        self.y = ((self.y as isize) + rhs.y) as usize;
+        self.0.push_back(snapshot);
     }
-}
 
-impl std::ops::Add<IVec2> for Vec2 {
-    type Output = Self;
-
-    fn add(mut self, rhs: IVec2) -> Self {
-        self += rhs;
-
-        self
+    fn pop(&mut self) -> Option<Snapshot> {
+        self.0.pop_back()
     }
-}
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum TileBackground {
-    Floor(Vec2),
-    Wall(Vec2),
-}
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum Furniture {
-    None,
-    Floor(Vec2),
-    Wall(Vec2),
-}
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum BodyLevel {
-    // CHOP THIS
-    Start,
-    One,
-    Two,
-    Three,
-
-    // BAG THIS
-    Four,
-    Five,
-    Six,
-    Seven,
-}
-
-#[derive(PartialEq)]
-enum BodyAction {
-    Chop,
-    Bag,
-}
-
-impl BodyLevel {
-    fn lower(self) -> Option<Self> {
-        match self {
-            BodyLevel::Start => Some(BodyLevel::One),
-            BodyLevel::One => Some(BodyLevel::Two),
-            BodyLevel::Two => Some(BodyLevel::Three),
-            BodyLevel::Three => Some(BodyLevel::Four),
-            BodyLevel::Four => Some(BodyLevel::Five),
-            BodyLevel::Five => Some(BodyLevel::Six),
-            BodyLevel::Six => Some(BodyLevel::Seven),
-            BodyLevel::Seven => None,
+    /// Files whatever the level just changed under the newest snapshot
+    fn record(&mut self, state: &LevelState) {
+        if let Some(newest) = self.0.back_mut() {
+            newest.record(state.changed_tiles());
         }
     }
 
-    fn action(&self) -> BodyAction {
-        match self {
-            BodyLevel::Start | BodyLevel::One | BodyLevel::Two | BodyLevel::Three => {
-                BodyAction::Chop
-            }
-            BodyLevel::Four | BodyLevel::Five | BodyLevel::Six | BodyLevel::Seven => {
-                BodyAction::Bag
-            }
+    /// Applies an action, keeping a snapshot from before it only if it changed the part of the
+    /// cleaner `undoable` picks out
+    fn apply<T: PartialEq>(
+        &mut self,
+        state: &mut LevelState,
+        cleaner: usize,
+        action: Action,
+        undoable: impl Fn(&Cleaner) -> T,
+    ) -> LevelFinished {
+        let before = undoable(&state.cleaners[cleaner]);
+        let snapshot = state.snapshot();
+        let finished = state.apply(cleaner, action);
+
+        if undoable(&state.cleaners[cleaner]) != before {
+            self.push(snapshot);
         }
-    }
-}
+        self.record(state);
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum Item {
-    None,
-    Body(BodyLevel, isize),
-    Knife,
-    Sponge,
-    Bleach,
-    Bag,
-    BagRoll,
-    BodyBag,
-}
-
-impl Item {
-    fn collidable(&self) -> bool {
-        match self {
-            Item::None
-            | Item::Body(_, _)
-            | Item::Knife
-            | Item::Sponge
-            | Item::Bleach
-            | Item::Bag
-            | Item::BagRoll => false,
-            Item::BodyBag => true,
-        }
-    }
-
-    fn is_cleaning_item(&self) -> bool {
-        match self {
-            Item::None
-            | Item::Body(_, _)
-            | Item::Knife
-            | Item::Bag
-            | Item::BagRoll
-            | Item::BodyBag => false,
-            Item::Sponge | Item::Bleach => true,
-        }
+        finished
     }
+}
 
-    fn draw(&self, location: Vec2, flip: bool) {
-        match self {
-            Item::None => {}
-            Item::Body(level, prgrss) => {
-                match level {
-                    BodyLevel::Start => {
-                        asset(vec2(8, 0), location).draw();
-                    }
-                    BodyLevel::One => asset(vec2(13, 0), location).draw(),
-                    BodyLevel::Two => {
-                        asset(vec2(13, 0), location).draw();
-                        asset(vec2(14, 0), location).draw();
-                    }
-                    BodyLevel::Three => {
-                        asset(vec2(13, 0), location).draw();
-                        asset(vec2(14, 0), location).draw();
-                        asset(vec2(15, 0), location).draw();
-                    }
-                    BodyLevel::Four => {
-                        asset(vec2(13, 0), location).draw();
-                        asset(vec2(14, 0), location).draw();
-                        asset(vec2(15, 0), location).draw();
-                        asset(vec2(16, 0), location).draw();
-                    }
-                    BodyLevel::Five => {
-                        asset(vec2(14, 0), location).draw();
-                        asset(vec2(15, 0), location).draw();
-                        asset(vec2(16, 0), location).draw();
-                    }
-                    BodyLevel::Six => {
-                        asset(vec2(15, 0), location).draw();
-                        asset(vec2(16, 0), location).draw();
-                    }
-                    BodyLevel::Seven => {
-                        asset(vec2(16, 0), location).draw();
-                    }
-                }
-                progress(location, (*prgrss as f32) / (BODY_CHOPPING_TIME as f32));
-            }
-            Item::Knife => asset(vec2(6, 2), location).flip_x(flip).draw(),
-            Item::Sponge => asset(vec2(7, 2), location).flip_x(flip).draw(),
-            Item::Bleach => asset(vec2(4, 2), location).flip_x(flip).draw(),
-            Item::Bag => asset(vec2(9, 3), location).flip_x(flip).draw(),
-            Item::BagRoll => asset(vec2(9, 2), location).flip_x(flip).draw(),
-            Item::BodyBag => {
-                asset(vec2(8, 2), location + ivec2(0, -1))
-                    .flip_x(flip)
-                    .draw();
-                asset(vec2(8, 3), location).flip_x(flip).draw()
-            }
+/// Optional rules picked on the level select screen
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy, Default)]
+struct Modifiers {
+    // The police show up once the two star par time is up
+    police: bool,
+    // Venti blood slowly spreads to the tiles around it
+    pooling: bool,
+}
+
+impl Modifiers {
+    fn of(state: &LevelState) -> Self {
+        Modifiers {
+            police: state.police_arrive_at.is_some(),
+            pooling: state.pooling,
         }
     }
 }
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-enum BloodLevel {
-    None,
-    Tall(isize),
-    Grande(isize),
-    Venti(isize),
+/// How a level that was just finished went
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+struct Completion {
+    level_index: usize,
+    characters: Vec<Character>,
+    // Including the level that was just finished
+    run: Stats,
+    elapsed: usize,
+    stars: u8,
+    new_best: bool,
+    modifiers: Modifiers,
 }
 
-impl BloodLevel {
-    /// Returns whether incrementing succeeded or not
-    fn increment(&mut self) -> bool {
-        match self {
-            BloodLevel::None => {
-                *self = BloodLevel::Tall(CLEANING_TIME);
-                true
-            }
-            BloodLevel::Tall(_) => {
-                *self = BloodLevel::Grande(CLEANING_TIME);
-                true
-            }
-            BloodLevel::Grande(_) => {
-                *self = BloodLevel::Venti(CLEANING_TIME);
-                true
-            }
-            BloodLevel::Venti(_) => false,
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+enum Screen {
+    Title {
+        character: Character,
+    },
+    LevelSelect {
+        selected: usize,
+        character: Character,
+        modifiers: Modifiers,
+    },
+    Playing {
+        level_index: usize,
+        state: LevelState,
+        history: History,
+        // Stats of the levels already finished in this run
+        run: Stats,
+        recording: Replay,
+        // Frames start has been held down for
+        start_held: usize,
+    },
+    Replaying {
+        level_index: usize,
+        state: LevelState,
+        history: History,
+        run: Stats,
+        replay: Replay,
+        frame: usize,
+    },
+    LevelComplete(Completion),
+    Busted {
+        level_index: usize,
+        state: LevelState,
+        run: Stats,
+    },
+    End {
+        stats: Stats,
+        characters: Vec<Character>,
+    },
+}
+
+impl Screen {
+    /// Starts with the cursor on the furthest level unlocked so far
+    fn level_select(character: Character, records: &Records) -> Screen {
+        let selected = (0..levels::all().len())
+            .rev()
+            .find(|&level_index| records.unlocked(level_index))
+            .unwrap_or(0);
+
+        Self::LevelSelect {
+            selected,
+            character,
+            modifiers: Modifiers::default(),
         }
     }
 
-    /// Returns whether decrementing succeeded or not
-    fn decrement(&mut self) -> bool {
-        match self {
-            BloodLevel::None => false,
-            BloodLevel::Tall(_) => {
-                *self = BloodLevel::None;
-                true
-            }
-            BloodLevel::Grande(_) => {
-                *self = BloodLevel::Tall(CLEANING_TIME);
-                true
-            }
-            BloodLevel::Venti(_) => {
-                *self = BloodLevel::Grande(CLEANING_TIME);
-                true
-            }
+    fn playing(
+        level_index: usize,
+        characters: &[Character],
+        run: Stats,
+        modifiers: Modifiers,
+    ) -> Screen {
+        let mut state = LevelState::spawn(level_index, characters);
+        state.police_arrive_at = modifiers
+            .police
+            .then(|| levels::all()[level_index].par.two_stars * TICKS_PER_SECOND);
+        state.pooling = modifiers.pooling;
+
+        Self::Playing {
+            level_index,
+            recording: Replay::new(&state),
+            state,
+            history: History::default(),
+            run,
+            start_held: 0,
         }
     }
 
-    fn draw(&self, location: Vec2) {
-        match self {
-            BloodLevel::None => unreachable!(),
-            level @ (BloodLevel::Tall(prgrss)
-            | BloodLevel::Grande(prgrss)
-            | BloodLevel::Venti(prgrss)) => {
-                if *prgrss != CLEANING_TIME {
-                    progress(location, (*prgrss as f32) / (CLEANING_TIME as f32));
-                }
-
-                asset(
-                    match level {
-                        BloodLevel::None => unreachable!(),
-                        BloodLevel::Tall(_) => vec2(7, 1),
-                        BloodLevel::Grande(_) => vec2(5, 1),
-                        BloodLevel::Venti(_) => vec2(6, 1),
-                    },
-                    location,
-                )
-                .draw()
-            }
-        };
+    /// Starts a level over with the same cleaners and modifiers, keeping the run's clock going
+    fn retry(level_index: usize, run: Stats, abandoned: &LevelState) -> Screen {
+        let mut state = LevelState::spawn(level_index, &abandoned.characters());
+        state.stats.ticks = abandoned.stats.ticks;
+        state.police_arrive_at = abandoned.police_arrive_at;
+        state.pooling = abandoned.pooling;
+
+        Self::Playing {
+            level_index,
+            recording: Replay::new(&state),
+            state,
+            history: History::default(),
+            run,
+            start_held: 0,
+        }
     }
-}
-
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
-struct Tile {
-    background: TileBackground,
-    item: Item,
-    furniture: Furniture,
-    foreground: Option<Vec2>,
-    player: bool,
-    drop_point: bool,
-    blood_level: BloodLevel,
-}
-
-enum Parity {
-    Even,
-    Odd,
-}
 
-impl From<u32> for Parity {
-    fn from(value: u32) -> Parity {
-        if value % 2 == 0 {
-            Parity::Even
-        } else {
-            Parity::Odd
+    fn replaying(level_index: usize, run: Stats, replay: Replay) -> Screen {
+        Self::Replaying {
+            level_index,
+            state: replay.initial_state(level_index),
+            history: History::default(),
+            run,
+            replay,
+            frame: 0,
         }
     }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
-struct Grid(Vec<Vec<Tile>>);
-
-impl Grid {
-    fn tutorial() -> (Self, Vec2) {
-        let (grid, character_position) = tiles::create_level_0();
-
-        (Grid(grid), character_position)
-    }
-
-    fn level_1() -> (Self, Vec2) {
-        let (grid, character_position) = tiles::create_level_1();
-
-        (Grid(grid), character_position)
-    }
-
-    fn iter(&self) -> std::slice::Iter<'_, Vec<Tile>> {
-        self.0.iter()
-    }
+struct GameState {
+    screen: Screen,
+    // Kept across runs, and across sessions since the whole state is saved
+    records: Records,
 }
 
-impl std::ops::Index<Vec2> for Grid {
-    type Output = Tile;
-
-    fn index(&self, index: Vec2) -> &Self::Output {
-        &self.0[index.x][index.y]
+impl Default for GameState {
+    fn default() -> Self {
+        GameState {
+            screen: Screen::Title {
+                character: Character::TheCat,
+            },
+            records: Records::default(),
+        }
     }
 }
 
-impl std::ops::IndexMut<Vec2> for Grid {
-    fn index_mut(&mut self, index: Vec2) -> &mut Self::Output {
-        &mut self.0[index.x][index.y]
-    }
+enum LevelUpdate {
+    Update(LevelState),
+    NextLevel(LevelState),
+    Restart(LevelState),
+    Busted(LevelState),
 }
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
-struct LevelState {
-    // Gameplay:
-    grid: Grid,
-    blood_on_boots: BloodLevel,
-    facing: Direction,
-    inventory: Item,
-
-    // Restrictions:
-    disable_move_until: usize,
-    last_frame_directions: HashSet<Direction>,
+fn update_level(mut state: LevelState, history: &mut History, input: &FrameInput) -> LevelUpdate {
+    state.stats.ticks += 1;
+    state.elapsed += 1;
 
-    // Performance:
-    character_position: Vec2,
+    if state.police_arrived() {
+        return LevelUpdate::Busted(state);
+    }
 
-    // Fun
-    character: Character,
-}
+    state.spread_blood();
+    history.record(&state);
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
-enum GameState {
-    Tutorial(LevelState),
-    Level1(LevelState),
-    EndScreen,
-}
+    // Pressing A on the next free gamepad brings in another cleaner
+    let joining = state.cleaners.len();
+    if joining < PLAYERS && input.gamepad(joining).a.just_pressed() {
+        let character = state.cleaners[joining - 1].character.next();
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, PartialOrd, Eq, Hash, Debug, Clone, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl From<Direction> for IVec2 {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::Up => ivec2(0, -1),
-            Direction::Down => ivec2(0, 1),
-            Direction::Left => ivec2(-1, 0),
-            Direction::Right => ivec2(1, 0),
+        // Snapshots from before the join don't know about the new cleaner
+        if state.join(character) {
+            *history = History::default();
         }
     }
-}
-
-enum LevelFinished {
-    Yes,
-    No,
-}
 
-impl LevelState {
-    fn move_player(&mut self, direction: Direction) {
-        let previous_position = self.character_position;
-        self.facing = direction;
-        let new_position = self.character_position + IVec2::from(direction);
-        if let TileBackground::Wall(_) = self.grid[new_position].background {
-            return;
-        }
-        if let Furniture::Wall(_) = self.grid[new_position].furniture {
-            return;
-        }
-        if self.grid[new_position].item.collidable() {
-            return;
-        }
-        self.character_position = new_position;
+    for cleaner in 0..state.cleaners.len() {
+        let pad = input.gamepad(cleaner);
 
-        if let Item::Body(_, _) = self.grid[new_position].item {
-            self.grid[new_position].blood_level = BloodLevel::Venti(CLEANING_TIME);
-            self.blood_on_boots = BloodLevel::Venti(CLEANING_TIME);
-        }
-
-        if self.blood_on_boots != BloodLevel::None {
-            let some_blood_drained = self.grid[new_position].blood_level.increment();
-            if some_blood_drained {
-                self.blood_on_boots.decrement();
+        if state.cleaners[cleaner].disable_move_until <= input.tick {
+            let mut pressed = HashSet::new();
+            if pad.up.pressed() {
+                pressed.insert(Direction::Up);
+            }
+            if pad.down.pressed() {
+                pressed.insert(Direction::Down);
+            }
+            if pad.left.pressed() {
+                pressed.insert(Direction::Left);
+            }
+            if pad.right.pressed() {
+                pressed.insert(Direction::Right);
             }
-        }
 
-        self.grid[previous_position].player = false;
-        self.grid[new_position].player = true
-    }
+            let direction = if pressed.len() >= 2 {
+                // The newest direction wins, looked for in a fixed order rather than the set's
+                // own so a replay picks the same one
+                let last_frame = &state.cleaners[cleaner].last_frame_directions;
+                let newest = [
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Left,
+                    Direction::Right,
+                ]
+                .into_iter()
+                .find(|direction| pressed.contains(direction) && !last_frame.contains(direction));
+
+                Some(newest.unwrap_or(state.cleaners[cleaner].facing))
+            } else {
+                pressed.iter().next().copied()
+            };
 
-    fn in_front_of_player(&self) -> Vec2 {
-        self.character_position + IVec2::from(self.facing)
-    }
+            if let Some(direction) = direction {
+                history.apply(&mut state, cleaner, Action::Move(direction), |cleaner| {
+                    cleaner.position
+                });
+                state.cleaners[cleaner].disable_move_until = input.tick + FRAMES_BETWEEN_MOVES;
+            }
 
-    fn is_finished(&self) -> bool {
-        !self.grid.iter().any(|column| {
-            column.iter().any(|cell| {
-                if cell.blood_level != BloodLevel::None {
-                    return true;
-                }
+            state.cleaners[cleaner].last_frame_directions = pressed;
+        }
 
-                matches!(cell.item, Item::Body(_, _) | Item::BodyBag)
-            })
-        })
-    }
+        if pad.a.pressed() {
+            let finished = history.apply(&mut state, cleaner, Action::Interact, |cleaner| {
+                cleaner.inventory
+            });
 
-    fn interact(&mut self) -> LevelFinished {
-        let in_front_of_player = self.in_front_of_player();
-
-        if self.inventory.is_cleaning_item() {
-            match &mut self.grid[in_front_of_player].blood_level {
-                BloodLevel::None => {}
-                BloodLevel::Tall(progress)
-                | BloodLevel::Grande(progress)
-                | BloodLevel::Venti(progress) => {
-                    *progress -= 1;
-
-                    if *progress <= 0 {
-                        self.grid[in_front_of_player].blood_level.decrement();
-
-                        if self.grid[in_front_of_player].blood_level == BloodLevel::None
-                            && self.is_finished()
-                        {
-                            return LevelFinished::Yes;
-                        }
-                    }
-                }
+            if let LevelFinished::Yes = finished {
+                return LevelUpdate::NextLevel(state);
             }
         }
 
-        match &mut self.grid[in_front_of_player].item {
-            Item::None => LevelFinished::No,
-            Item::Body(level, progress) => {
-                if level.action() == BodyAction::Bag {
-                    if self.inventory != Item::Bag {
-                        return LevelFinished::No;
-                    }
-
-                    *progress -= 1;
-
-                    if *progress <= 0 {
-                        self.inventory = Item::None;
-
-                        match level.lower() {
-                            Some(new_level) => {
-                                self.grid[in_front_of_player].item =
-                                    Item::Body(new_level, BODY_CHOPPING_TIME);
-                            }
-                            None => {
-                                self.grid[in_front_of_player].item = Item::None;
-                            }
-                        }
-
-                        let mut around_body = ivec2(0, 1);
-                        let empty_near_body = loop {
-                            let cell = in_front_of_player + around_body;
-                            if self.grid[cell].item == Item::None {
-                                break cell;
-                            }
-
-                            around_body = around_body.clockwise();
-                        };
-                        self.grid[empty_near_body].item = Item::BodyBag;
-                    }
-
-                    return LevelFinished::No;
-                }
+        if pad.b.pressed() {
+            let finished = history.apply(&mut state, cleaner, Action::Drop, |cleaner| {
+                cleaner.inventory
+            });
 
-                if self.inventory != Item::Knife {
-                    return LevelFinished::No;
-                }
+            if let LevelFinished::Yes = finished {
+                return LevelUpdate::NextLevel(state);
+            }
+        }
 
-                *progress -= 1;
+        if pad.y.just_pressed() {
+            if let Some(snapshot) = history.pop() {
+                state.rewind(snapshot);
+            }
+        }
 
-                if *progress <= 0 {
-                    let Some(new_level) = level.lower() else {
-                        self.grid[in_front_of_player].item = Item::None;
-                        return LevelFinished::No;
-                    };
-                    self.grid[in_front_of_player].item = Item::Body(new_level, BODY_CHOPPING_TIME);
-                }
+        if pad.select.just_pressed() {
+            return LevelUpdate::Restart(state);
+        }
 
-                LevelFinished::No
-            }
-            item @ (Item::Knife | Item::Sponge | Item::Bleach | Item::Bag | Item::BodyBag) => {
-                if self.inventory != Item::None {
-                    return LevelFinished::No;
-                }
+        if pad.x.just_pressed() {
+            let character = &mut state.cleaners[cleaner].character;
+            *character = character.next();
+        }
+    }
 
-                self.inventory = *item;
-                self.grid[in_front_of_player].item = Item::None;
+    LevelUpdate::Update(state)
+}
 
-                LevelFinished::No
-            }
-            Item::BagRoll => {
-                if self.inventory != Item::None {
-                    return LevelFinished::No;
-                }
+/// Feeds a whole log through `update_level` the way a skipped replay does, stopping early if
+/// the level comes to an end. Replays only hold up as long as this always comes out the same
+pub fn play_back(mut state: LevelState, log: &InputLog) -> LevelState {
+    let mut history = History::default();
+    let mut frame = 0;
 
-                self.inventory = Item::Bag;
+    while let Some(input) = log.frame(frame) {
+        frame += 1;
 
-                LevelFinished::No
-            }
+        match update_level(state, &mut history, &input) {
+            LevelUpdate::Update(next) => state = next,
+            LevelUpdate::NextLevel(state)
+            | LevelUpdate::Restart(state)
+            | LevelUpdate::Busted(state) => return state,
         }
     }
 
-    fn drop(&mut self) -> LevelFinished {
-        let in_front_of_player = self.in_front_of_player();
+    state
+}
 
-        if self.grid[in_front_of_player].drop_point && self.inventory == Item::BodyBag {
-            self.inventory = Item::None;
-            return match self.is_finished() {
-                true => LevelFinished::Yes,
-                false => LevelFinished::No,
-            };
-        }
+fn draw_level(level_index: usize, state: &mut LevelState) {
+    // Keep every cleaner in frame, gliding along as they walk
+    let targets = state
+        .cleaners
+        .iter()
+        .map(|cleaner| {
+            let (x, y) = draw::cleaner_location(cleaner, state.elapsed);
+            (
+                x + draw::CELL_SIZE as f32 / 2.0,
+                y + draw::CELL_SIZE as f32 / 2.0,
+            )
+        })
+        .collect::<Vec<_>>();
+    let [width, height] = canvas_size!();
+    state.camera.follow(
+        &targets,
+        (
+            (state.grid.width() * draw::CELL_SIZE) as f32,
+            (state.grid.height() * draw::CELL_SIZE) as f32,
+        ),
+        (width as f32, height as f32),
+    );
 
-        if self.grid[in_front_of_player].item != Item::None {
-            return LevelFinished::No;
-        }
+    draw::draw_level(state);
+    set_cam!(
+        x = state.camera.x,
+        y = state.camera.y,
+        z = state.camera.zoom
+    );
 
-        self.grid[in_front_of_player].item = self.inventory;
-        self.inventory = Item::None;
+    hud::draw_hud(&levels::all()[level_index].name, state);
+}
 
-        LevelFinished::No
-    }
+fn format_time(ticks: usize) -> String {
+    let seconds = ticks / TICKS_PER_SECOND;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
 }
 
-impl LevelState {
-    fn new(data: (Grid, Vec2)) -> Self {
-        LevelState {
-            grid: data.0,
-            inventory: Item::None,
-            blood_on_boots: BloodLevel::None,
-            facing: Direction::Down,
-            character_position: data.1,
-            disable_move_until: 0,
-            last_frame_directions: HashSet::new(),
-            character: Character::TheCat,
-        }
-    }
+fn format_stars(stars: u8) -> String {
+    format!("{:-<3}", "*".repeat(stars as usize))
 }
 
-impl GameState {
-    fn level_1() -> GameState {
-        Self::Level1(LevelState::new(Grid::level_1()))
-    }
+fn draw_lines(lines: &[String]) {
+    lines.iter().enumerate().for_each(|(index, line)| {
+        text!(
+            line,
+            x = 8,
+            y = 8 + index * 12,
+            font = Font::M,
+            absolute = true
+        );
+    });
 }
 
-impl Default for GameState {
-    fn default() -> Self {
-        Self::Tutorial(LevelState::new(Grid::tutorial()))
+/// Moves on from a level that was finished or abandoned
+fn leave_level(
+    level_index: usize,
+    run: Stats,
+    update: LevelUpdate,
+    records: &mut Records,
+) -> Screen {
+    match update {
+        LevelUpdate::Update(_) => unreachable!("The level is still being played"),
+        LevelUpdate::NextLevel(finished) => {
+            let stars = levels::all()[level_index].par.stars(finished.elapsed);
+            let new_best = records.update(level_index, finished.elapsed, stars);
+
+            Screen::LevelComplete(Completion {
+                level_index,
+                characters: finished.characters(),
+                run: run + finished.stats,
+                elapsed: finished.elapsed,
+                stars,
+                new_best,
+                modifiers: Modifiers::of(&finished),
+            })
+        }
+        LevelUpdate::Restart(abandoned) => Screen::retry(level_index, run, &abandoned),
+        LevelUpdate::Busted(state) => Screen::Busted {
+            level_index,
+            state,
+            run,
+        },
     }
 }
 
-#[must_use]
-struct Asset {
-    sprite: Vec2,
-    location: Vec2,
-    opacity: f32,
-    flip_x: bool,
-}
+fn update_level_complete(completion: Completion, records: &Records) -> Screen {
+    let level = &levels::all()[completion.level_index];
+    let last_level = completion.level_index + 1 == levels::all().len();
+    let best = match (completion.new_best, records.get(completion.level_index)) {
+        (true, _) => "New best time!".to_string(),
+        (false, Some(record)) => format!("Best: {}", format_time(record.ticks)),
+        (false, None) => String::new(),
+    };
+
+    draw_lines(&[
+        format!("{} CLEAN!", level.name),
+        format!("Time: {}", format_time(completion.elapsed)),
+        format!("Stars: {}", format_stars(completion.stars)),
+        best,
+        format!(
+            "Par: {} for **-, {} for ***",
+            format_time(level.par.two_stars * TICKS_PER_SECOND),
+            format_time(level.par.three_stars * TICKS_PER_SECOND)
+        ),
+        String::new(),
+        match last_level {
+            true => "A: Finish".to_string(),
+            false => "A: Next level".to_string(),
+        },
+    ]);
+
+    if gamepad(0).a.just_pressed() {
+        return match last_level {
+            true => Screen::End {
+                stats: completion.run,
+                characters: completion.characters,
+            },
+            false => Screen::playing(
+                completion.level_index + 1,
+                &completion.characters,
+                completion.run,
+                completion.modifiers,
+            ),
+        };
+    }
 
-fn asset(sprite: Vec2, location: Vec2) -> Asset {
-    Asset::new(sprite, location)
+    Screen::LevelComplete(completion)
 }
 
-impl Asset {
-    fn new(sprite: Vec2, location: Vec2) -> Asset {
-        Asset {
-            sprite,
-            location,
-            opacity: 1.0,
-            flip_x: false,
-        }
+fn update_busted(
+    level_index: usize,
+    mut state: LevelState,
+    run: Stats,
+    records: &Records,
+) -> Screen {
+    draw_level(level_index, &mut state);
+    draw_lines(&[
+        "BUSTED!".to_string(),
+        "The police got here first".to_string(),
+        String::new(),
+        "A: Try again".to_string(),
+        "B: Level select".to_string(),
+    ]);
+
+    let pad = gamepad(0);
+
+    if pad.a.just_pressed() {
+        return Screen::retry(level_index, run, &state);
     }
 
-    #[allow(clippy::neg_multiply)]
-    fn draw(self) {
-        sprite!(
-            "assets",
-            x = CELL_SIZE * self.location.x,
-            y = CELL_SIZE * self.location.y,
-            w = CELL_SIZE,
-            h = CELL_SIZE,
-            sw = CELL_SIZE,
-            sh = CELL_SIZE,
-            sx = self.sprite.x * 16,
-            sy = self.sprite.y * 16,
-            opacity = self.opacity,
-            flip_x = self.flip_x
-        );
+    if pad.b.just_pressed() {
+        return Screen::level_select(state.cleaners[0].character, records);
     }
 
-    fn flip_x(self, flip_x: bool) -> Asset {
-        Asset { flip_x, ..self }
+    Screen::Busted {
+        level_index,
+        state,
+        run,
     }
 }
 
-fn progress(location: Vec2, progress: f32) {
-    let rect_size = lerp(PROGRESS_BAR_SIZE, 0, progress);
-    rect!(
-        x = CELL_SIZE * location.x + (CELL_SIZE - PROGRESS_BAR_SIZE) / 2,
-        y = CELL_SIZE * location.y - CELL_SIZE / 4,
-        w = PROGRESS_BAR_SIZE,
-        h = 2,
-        color = 0x888888ff,
-    );
-    rect!(
-        x = CELL_SIZE * location.x + (CELL_SIZE - PROGRESS_BAR_SIZE) / 2,
-        y = CELL_SIZE * location.y - CELL_SIZE / 4,
-        w = rect_size,
-        h = 2,
-        color = 0x00ff00ff,
+fn update_title(character: Character, records: &Records) -> Screen {
+    text!(
+        "THE CLEANERS",
+        x = 8,
+        y = 8,
+        font = Font::L,
+        absolute = true
     );
-}
+    text!("A: Start", x = 8, y = 32, font = Font::M, absolute = true);
 
-fn lerp(start: usize, end: usize, t: f32) -> usize {
-    ((start as f32) * (1.0 - t) + (end as f32) * t).round() as usize
-}
+    if gamepad(0).a.just_pressed() {
+        return Screen::level_select(character, records);
+    }
 
-enum LevelUpdate {
-    Update(LevelState),
-    NextLevel,
+    Screen::Title { character }
 }
 
-fn update_level(mut state: LevelState) -> LevelUpdate {
-    state.grid.iter().enumerate().for_each(|(row_index, row)| {
-        row.iter().enumerate().for_each(|(column_index, cell)| {
-            let location = vec2(row_index, column_index);
-            asset(
-                match cell.background {
-                    TileBackground::Floor(sprite) => sprite,
-                    TileBackground::Wall(sprite) => sprite,
-                },
-                location,
-            )
-            .draw();
+fn update_level_select(
+    mut selected: usize,
+    character: Character,
+    mut modifiers: Modifiers,
+    records: &Records,
+) -> Screen {
+    let pad = gamepad(0);
 
-            if cell.blood_level != BloodLevel::None {
-                cell.blood_level.draw(location);
-            }
+    if pad.up.just_pressed() {
+        selected = selected.saturating_sub(1);
+    }
+    if pad.down.just_pressed() {
+        selected = (selected + 1).min(levels::all().len() - 1);
+    }
+    if pad.x.just_pressed() {
+        modifiers.police = !modifiers.police;
+    }
+    if pad.y.just_pressed() {
+        modifiers.pooling = !modifiers.pooling;
+    }
 
-            if let Furniture::Floor(sprite) | Furniture::Wall(sprite) = cell.furniture {
-                asset(sprite, location).draw();
-            }
+    let levels = levels::all()
+        .iter()
+        .enumerate()
+        .map(|(level_index, level)| {
+            let cursor = match level_index == selected {
+                true => ">",
+                false => " ",
+            };
+            let status = match (records.unlocked(level_index), records.get(level_index)) {
+                (false, _) => "LOCKED".to_string(),
+                (true, None) => "NEW".to_string(),
+                (true, Some(record)) => {
+                    format!(
+                        "{} {}",
+                        format_time(record.ticks),
+                        format_stars(record.stars)
+                    )
+                }
+            };
 
-            cell.item.draw(location, false);
+            format!("{cursor} {}  {status}", level.name)
+        });
+
+    let lines = ["SELECT A LEVEL".to_string(), String::new()]
+        .into_iter()
+        .chain(levels)
+        .chain([
+            String::new(),
+            "A: Play".to_string(),
+            match modifiers.police {
+                true => "X: Police ON".to_string(),
+                false => "X: Police OFF".to_string(),
+            },
+            match modifiers.pooling {
+                true => "Y: Pooling blood ON".to_string(),
+                false => "Y: Pooling blood OFF".to_string(),
+            },
+            "B: Back".to_string(),
+        ])
+        .collect::<Vec<_>>();
+
+    draw_lines(&lines);
+
+    if pad.a.just_pressed() && records.unlocked(selected) {
+        return Screen::playing(selected, &[character], Stats::default(), modifiers);
+    }
+
+    if pad.b.just_pressed() {
+        return Screen::Title { character };
+    }
+
+    Screen::LevelSelect {
+        selected,
+        character,
+        modifiers,
+    }
+}
+
+fn update_end_screen(stats: Stats, characters: Vec<Character>, records: &Records) -> Screen {
+    let last_level = levels::all().len() - 1;
+    let bests = levels::all()
+        .iter()
+        .enumerate()
+        .map(|(level_index, level)| match records.get(level_index) {
+            Some(record) => format!(
+                "{}: best {} {}",
+                level.name,
+                format_time(record.ticks),
+                format_stars(record.stars)
+            ),
+            None => format!("{}: not finished yet", level.name),
+        });
+
+    let lines = [
+        "ALL CLEAN!".to_string(),
+        format!("Time: {}", format_time(stats.ticks)),
+        format!("Body bags delivered: {}", stats.body_bags_delivered),
+        format!("Blood tiles cleaned: {}", stats.blood_tiles_cleaned),
+        format!(
+            "Cleaners: {}",
+            characters
+                .iter()
+                .map(|character| character.name())
+                .collect::<Vec<_>>()
+                .join(" & ")
+        ),
+        String::new(),
+    ]
+    .into_iter()
+    .chain(bests)
+    .chain([
+        String::new(),
+        "A: Level select".to_string(),
+        format!("B: Replay {}", levels::all()[last_level].name),
+    ])
+    .collect::<Vec<_>>();
+
+    draw_lines(&lines);
 
-            if cell.player {
-                let character_sprite_locations = match state.character {
-                    Character::TheCat => THE_CAT,
-                    Character::TwoToes => TWO_TOES,
-                    Character::MachineGun => MACHINE_GUN,
-                    Character::OneEye => ONE_EYE,
-                };
-                let (sprite, flip) = match state.facing {
-                    Direction::Up => (character_sprite_locations.up, false),
-                    Direction::Down => (character_sprite_locations.down, false),
-                    Direction::Left => (character_sprite_locations.right, true),
-                    Direction::Right => (character_sprite_locations.right, false),
-                };
-                asset(sprite, location).flip_x(flip).draw();
-            }
+    let pad = gamepad(0);
 
-            if let Some(foreground) = cell.foreground {
-                asset(foreground, location).draw();
-            }
+    if pad.a.just_pressed() {
+        return Screen::level_select(characters[0], records);
+    }
 
-            let in_front_of_player = state.in_front_of_player();
+    if pad.b.just_pressed() {
+        return Screen::playing(
+            last_level,
+            &characters,
+            Stats::default(),
+            Modifiers::default(),
+        );
+    }
 
-            state
-                .inventory
-                .draw(in_front_of_player, matches!(state.facing, Direction::Left));
-        })
-    });
+    Screen::End { stats, characters }
+}
+
+fn update_screen(screen: Screen, records: &mut Records) -> Screen {
+    match screen {
+        Screen::Title { character } => update_title(character, records),
+        Screen::LevelSelect {
+            selected,
+            character,
+            modifiers,
+        } => update_level_select(selected, character, modifiers, records),
+        Screen::Playing {
+            level_index,
+            state,
+            mut history,
+            run,
+            mut recording,
+            start_held,
+        } => {
+            let input = FrameInput::read();
+            let start = input.gamepad(0).start;
+
+            if start.just_released() && start_held < HOLD_FOR_OVERVIEW {
+                return Screen::replaying(level_index, run, recording);
+            }
 
-    let pad = gamepad(0);
+            recording.log.record(input);
 
-    if state.disable_move_until <= tick() {
-        let mut pressed = HashSet::new();
-        if pad.up.pressed() {
-            pressed.insert(Direction::Up);
-        }
-        if pad.down.pressed() {
-            pressed.insert(Direction::Down);
-        }
-        if pad.left.pressed() {
-            pressed.insert(Direction::Left);
-        }
-        if pad.right.pressed() {
-            pressed.insert(Direction::Right);
-        }
-
-        let direction = if pressed.len() >= 2 {
-            let mut difference = pressed.difference(&state.last_frame_directions);
+            match update_level(state, &mut history, &input) {
+                LevelUpdate::Update(mut state) => {
+                    let start_held = match start.pressed() {
+                        true => start_held + 1,
+                        false => 0,
+                    };
 
-            let direction = difference.next();
+                    // The camera isn't part of the replayed input, so the overview sits outside
+                    // of `update_level`, shown for as long as start is held
+                    state.camera.overview = start_held >= HOLD_FOR_OVERVIEW;
 
-            Some(direction.copied().unwrap_or(state.facing))
-        } else if pressed.len() == 1 {
-            Some(pressed.into_iter().next().expect("unreachable"))
-        } else {
-            None
-        };
+                    draw_level(level_index, &mut state);
 
-        if let Some(direction) = direction {
-            state.move_player(direction);
-            state.disable_move_until = tick() + FRAMES_BETWEEN_MOVES;
+                    Screen::Playing {
+                        level_index,
+                        state,
+                        history,
+                        run,
+                        recording,
+                        start_held,
+                    }
+                }
+                update => leave_level(level_index, run, update, records),
+            }
         }
-    }
+        Screen::Replaying {
+            level_index,
+            mut state,
+            mut history,
+            run,
+            replay,
+            mut frame,
+        } => {
+            // Tapping start again skips straight to the end of the replay. Going on the release,
+            // like the tap that started it, keeps it from starting another one back in the level
+            let skip = gamepad(0).start.just_released();
+
+            loop {
+                let Some(input) = replay.log.frame(frame) else {
+                    draw_level(level_index, &mut state);
+
+                    return Screen::Playing {
+                        level_index,
+                        state,
+                        history,
+                        run,
+                        recording: replay,
+                        start_held: 0,
+                    };
+                };
+                frame += 1;
 
-    if pad.a.pressed() {
-        if let LevelFinished::Yes = state.interact() {
-            return LevelUpdate::NextLevel;
-        }
-    }
+                match update_level(state, &mut history, &input) {
+                    LevelUpdate::Update(next) => state = next,
+                    update => return leave_level(level_index, run, update, records),
+                }
 
-    if pad.b.pressed() {
-        if let LevelFinished::Yes = state.drop() {
-            return LevelUpdate::NextLevel;
-        }
-    }
+                if !skip {
+                    break;
+                }
+            }
 
-    if pad.x.just_pressed() {
-        state.character = match state.character {
-            Character::TheCat => Character::TwoToes,
-            Character::TwoToes => Character::MachineGun,
-            Character::MachineGun => Character::OneEye,
-            Character::OneEye => Character::TheCat,
+            draw_level(level_index, &mut state);
+            text!("REPLAY", x = 8, y = 8, font = Font::L, absolute = true);
+
+            Screen::Replaying {
+                level_index,
+                state,
+                history,
+                run,
+                replay,
+                frame,
+            }
         }
+        Screen::LevelComplete(completion) => update_level_complete(completion, records),
+        Screen::Busted {
+            level_index,
+            state,
+            run,
+        } => update_busted(level_index, state, run, records),
+        Screen::End { stats, characters } => update_end_screen(stats, characters, records),
     }
-
-    set_cam!(
-        x = state.character_position.x * 16,
-        y = state.character_position.y * 16
-    );
-
-    LevelUpdate::Update(state)
 }
 
-fn update(state: GameState) -> GameState {
-    match state {
-        GameState::Tutorial(state) => match update_level(state) {
-            LevelUpdate::Update(level_state) => GameState::Tutorial(level_state),
-            LevelUpdate::NextLevel => GameState::level_1(),
-        },
-        GameState::Level1(state) => match update_level(state) {
-            LevelUpdate::Update(level_state) => GameState::Level1(level_state),
-            LevelUpdate::NextLevel => GameState::EndScreen,
-        },
-        GameState::EndScreen => todo!(),
-    }
+fn update(
+    GameState {
+        screen,
+        mut records,
+    }: GameState,
+) -> GameState {
+    let screen = update_screen(screen, &mut records);
+
+    GameState { screen, records }
 }
 
 // UPDATE MACHINERY
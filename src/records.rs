@@ -0,0 +1,47 @@
+use turbo::borsh::{self, *};
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct Record {
+    pub ticks: usize,
+    pub stars: u8,
+}
+
+/// The best result on every level, indexed like `levels::all()`
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Default)]
+pub struct Records(Vec<Option<Record>>);
+
+impl Records {
+    pub fn get(&self, level_index: usize) -> Option<Record> {
+        self.0.get(level_index).copied().flatten()
+    }
+
+    /// The first level is always open, every other one opens once the level before it is done
+    pub fn unlocked(&self, level_index: usize) -> bool {
+        level_index == 0 || self.get(level_index - 1).is_some()
+    }
+
+    /// Keeps the fastest time and the most stars separately, in case the par times changed since
+    /// the best time was set. Returns whether `ticks` is a new best time
+    pub fn update(&mut self, level_index: usize, ticks: usize, stars: u8) -> bool {
+        if self.0.len() <= level_index {
+            self.0.resize(level_index + 1, None);
+        }
+
+        match &mut self.0[level_index] {
+            Some(record) => {
+                record.stars = record.stars.max(stars);
+
+                if ticks < record.ticks {
+                    record.ticks = ticks;
+                    return true;
+                }
+
+                false
+            }
+            empty @ None => {
+                *empty = Some(Record { ticks, stars });
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use turbo::borsh::{self, *};
+use turbo::prelude::*;
+
+use crate::level::{Character, LevelState};
+
+/// How many gamepads are read, and so how many cleaners can play at once
+pub const PLAYERS: usize = 2;
+
+/// Every gamepad button packed into two bits each, in the same order as turbo's `Gamepad`
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Pad(u32);
+
+impl From<Gamepad<Button>> for Pad {
+    fn from(gamepad: Gamepad<Button>) -> Self {
+        let gamepad: Gamepad<u8> = gamepad.into();
+        let buttons = [
+            gamepad.up,
+            gamepad.down,
+            gamepad.left,
+            gamepad.right,
+            gamepad.a,
+            gamepad.b,
+            gamepad.x,
+            gamepad.y,
+            gamepad.start,
+            gamepad.select,
+        ];
+
+        Pad(buttons
+            .iter()
+            .enumerate()
+            .fold(0, |packed, (index, button)| {
+                packed | (*button as u32 & 0b11) << (index * 2)
+            }))
+    }
+}
+
+impl From<Pad> for Gamepad<Button> {
+    fn from(pad: Pad) -> Self {
+        let button = |index: usize| Button::from(((pad.0 >> (index * 2)) & 0b11) as u8);
+
+        Gamepad {
+            up: button(0),
+            down: button(1),
+            left: button(2),
+            right: button(3),
+            a: button(4),
+            b: button(5),
+            x: button(6),
+            y: button(7),
+            start: button(8),
+            select: button(9),
+        }
+    }
+}
+
+/// Everything `update_level` reads from the outside world in a single frame
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+pub struct FrameInput {
+    pub tick: usize,
+    // One per cleaner, including one who hasn't joined yet
+    pub pads: [Pad; PLAYERS],
+}
+
+impl FrameInput {
+    pub fn read() -> Self {
+        FrameInput {
+            tick: tick(),
+            pads: std::array::from_fn(|player| gamepad(player as u32).into()),
+        }
+    }
+
+    pub fn gamepad(&self, player: usize) -> Gamepad<Button> {
+        self.pads[player].into()
+    }
+}
+
+/// A stretch of consecutive ticks during which the gamepad didn't change
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Copy)]
+struct InputRun {
+    tick: usize,
+    frames: usize,
+    pads: [Pad; PLAYERS],
+}
+
+/// Run-length encoded frame inputs, so that idling or holding a button costs a single entry
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone, Default)]
+pub struct InputLog(Vec<InputRun>);
+
+impl InputLog {
+    pub fn record(&mut self, input: FrameInput) {
+        if let Some(last) = self.0.last_mut() {
+            if last.pads == input.pads && last.tick + last.frames == input.tick {
+                last.frames += 1;
+                return;
+            }
+        }
+
+        self.0.push(InputRun {
+            tick: input.tick,
+            frames: 1,
+            pads: input.pads,
+        });
+    }
+
+    pub fn frame(&self, mut index: usize) -> Option<FrameInput> {
+        self.0.iter().find_map(|run| {
+            if index < run.frames {
+                return Some(FrameInput {
+                    tick: run.tick + index,
+                    pads: run.pads,
+                });
+            }
+
+            index -= run.frames;
+            None
+        })
+    }
+}
+
+/// The inputs of a level since it was (re)started, enough to play it back from scratch
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
+pub struct Replay {
+    characters: Vec<Character>,
+    ticks: usize,
+    police_arrive_at: Option<usize>,
+    pooling: bool,
+    pub log: InputLog,
+}
+
+impl Replay {
+    pub fn new(state: &LevelState) -> Self {
+        Replay {
+            characters: state.characters(),
+            ticks: state.stats.ticks,
+            police_arrive_at: state.police_arrive_at,
+            pooling: state.pooling,
+            log: InputLog::default(),
+        }
+    }
+
+    /// Rebuilds the level exactly as it was when recording started
+    pub fn initial_state(&self, level_index: usize) -> LevelState {
+        let mut state = LevelState::spawn(level_index, &self.characters);
+        state.stats.ticks = self.ticks;
+        state.police_arrive_at = self.police_arrive_at;
+        state.pooling = self.pooling;
+
+        state
+    }
+}
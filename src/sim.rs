@@ -0,0 +1,99 @@
+//! Headless harness for driving a [`LevelState`] without the turbo runtime, drawing or input
+
+use crate::level::{
+    vec2, Action, BloodLevel, BodyLevel, Character, Direction, Furniture, Grid, Item,
+    LevelFinished, LevelState, Spawn, Tile, TileBackground, BODY_CHOPPING_TIME, CLEANING_TIME,
+};
+
+/// Builds a level from ASCII art, one character per tile and one line per row.
+///
+/// - `#` wall, `.` floor, `@` player spawn
+/// - `B` body, `K` knife, `S` sponge, `L` bleach, `R` bag roll, `G` bag, `O` body bag
+/// - `D` drop point, `T` furniture that blocks the player, `W` sink, `M` doormat
+/// - `1`, `2`, `3` tall, grande and venti blood
+///
+/// Leading and trailing whitespace on each line is ignored and short rows are padded with walls.
+pub fn parse(ascii: &str) -> LevelState {
+    let rows = ascii
+        .lines()
+        .map(str::trim)
+        .filter(|row| !row.is_empty())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut character_position = None;
+    let mut columns = vec![Vec::with_capacity(rows.len()); width];
+
+    rows.iter().enumerate().for_each(|(y, row)| {
+        let mut row = row.chars().chain(std::iter::repeat('#'));
+
+        columns.iter_mut().enumerate().for_each(|(x, column)| {
+            let symbol = row.next().expect("unreachable");
+            let mut tile = Tile {
+                background: TileBackground::Floor(vec2(0, 0)),
+                item: Item::None,
+                furniture: Furniture::None,
+                foreground: None,
+                player: false,
+                drop_point: false,
+                blood_level: BloodLevel::None,
+                footprints: None,
+            };
+
+            match symbol {
+                '.' => {}
+                '#' => tile.background = TileBackground::Wall(vec2(0, 0)),
+                '@' => {
+                    tile.player = true;
+                    character_position = Some(vec2(x, y));
+                }
+                'B' => tile.item = Item::Body(BodyLevel::Start, BODY_CHOPPING_TIME),
+                'K' => tile.item = Item::Knife,
+                'S' => tile.item = Item::Sponge(0),
+                'L' => tile.item = Item::Bleach(0),
+                'R' => tile.item = Item::BagRoll,
+                'G' => tile.item = Item::Bag,
+                'O' => tile.item = Item::BodyBag,
+                'D' => tile.drop_point = true,
+                'T' => tile.furniture = Furniture::Wall(vec2(0, 0)),
+                'W' => tile.furniture = Furniture::Sink(vec2(0, 0)),
+                'M' => tile.furniture = Furniture::Doormat(vec2(0, 0)),
+                '1' => tile.blood_level = BloodLevel::Tall(CLEANING_TIME),
+                '2' => tile.blood_level = BloodLevel::Grande(CLEANING_TIME),
+                '3' => tile.blood_level = BloodLevel::Venti(CLEANING_TIME),
+                symbol => panic!("Unknown tile {symbol:?} at {x}, {y}"),
+            }
+
+            column.push(tile);
+        })
+    });
+
+    let Some(character_position) = character_position else {
+        panic!("No @ spawn point");
+    };
+
+    let spawn = Spawn {
+        position: character_position,
+        facing: Direction::Down,
+        character: None,
+    };
+
+    LevelState::new((Grid(columns), spawn), Character::TheCat)
+}
+
+/// Applies `actions` to the first cleaner in order, stopping early if one of them finishes the
+/// level
+pub fn run(state: &mut LevelState, actions: impl IntoIterator<Item = Action>) -> LevelFinished {
+    for action in actions {
+        if let LevelFinished::Yes = state.apply(0, action) {
+            return LevelFinished::Yes;
+        }
+    }
+
+    LevelFinished::No
+}
+
+/// Repeats `action` for `frames` frames, the way holding a button down would
+pub fn hold(action: Action, frames: usize) -> impl Iterator<Item = Action> {
+    std::iter::repeat_n(action, frames)
+}
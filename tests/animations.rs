@@ -0,0 +1,81 @@
+use the_cleaners::animation::{self, character, parse, Frame, Pose};
+use the_cleaners::level::{vec2, Character, Direction};
+
+#[test]
+fn frames_parse_with_optional_nudges() {
+    let animations = parse(
+        "
+        # A comment
+        wobble  4,0*3  4,0/-1,2*2
+        ",
+    );
+
+    let wobble = &animations["wobble"];
+    assert_eq!(wobble.duration(), 5);
+    assert_eq!(
+        wobble.frame(2),
+        &Frame {
+            sprite: vec2(4, 0),
+            nudge: (0, 0),
+            duration: 3
+        }
+    );
+    assert_eq!(
+        wobble.frame(3),
+        &Frame {
+            sprite: vec2(4, 0),
+            nudge: (-1, 2),
+            duration: 2
+        }
+    );
+    // Loops back around
+    assert_eq!(wobble.frame(5), wobble.frame(0));
+}
+
+#[test]
+#[should_panic(expected = "Bad frame \"4,0\" for still on line 1")]
+fn frames_need_a_duration() {
+    parse("still 4,0");
+}
+
+#[test]
+fn every_character_pose_and_direction_is_declared() {
+    let mut character_ = Character::TheCat;
+    for _ in 0..4 {
+        for pose in [Pose::Idle, Pose::Walk] {
+            for direction in [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ] {
+                character(character_, pose, direction);
+            }
+        }
+        character_ = character_.next();
+    }
+
+    for name in [
+        "knife.chop",
+        "sponge.scrub",
+        "bleach.scrub",
+        "bag.fill",
+        "sponge.drip",
+    ] {
+        animation::animation(name);
+    }
+}
+
+#[test]
+fn walking_steps_through_frames_of_its_own() {
+    let mut character_ = Character::TheCat;
+    for _ in 0..4 {
+        for direction in [Direction::Up, Direction::Down, Direction::Right] {
+            let standing = character(character_, Pose::Idle, direction).frame(0).sprite;
+            let walk = character(character_, Pose::Walk, direction);
+
+            assert_ne!(walk.frame(0).sprite, standing);
+        }
+        character_ = character_.next();
+    }
+}
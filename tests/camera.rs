@@ -0,0 +1,89 @@
+use the_cleaners::camera::Camera;
+
+const LEVEL: (f32, f32) = (640.0, 480.0);
+const VIEW: (f32, f32) = (256.0, 144.0);
+
+fn still() -> Camera {
+    Camera {
+        smoothing: 1.0,
+        ..Camera::default()
+    }
+}
+
+#[test]
+fn starts_centered_on_the_target() {
+    let mut camera = Camera::default();
+
+    camera.follow(&[(300.0, 200.0)], LEVEL, VIEW);
+
+    assert_eq!((camera.x, camera.y), (300.0, 200.0));
+}
+
+#[test]
+fn only_moves_once_the_target_leaves_the_dead_zone() {
+    let mut camera = still();
+    camera.follow(&[(300.0, 200.0)], LEVEL, VIEW);
+
+    camera.follow(&[(300.0 + camera.dead_zone.0, 200.0)], LEVEL, VIEW);
+    assert_eq!((camera.x, camera.y), (300.0, 200.0));
+
+    camera.follow(&[(300.0 + camera.dead_zone.0 + 10.0, 200.0)], LEVEL, VIEW);
+    assert_eq!((camera.x, camera.y), (310.0, 200.0));
+}
+
+#[test]
+fn smoothing_eases_towards_the_target() {
+    let mut camera = Camera {
+        dead_zone: (0.0, 0.0),
+        smoothing: 0.5,
+        ..Camera::default()
+    };
+    camera.follow(&[(300.0, 200.0)], LEVEL, VIEW);
+
+    camera.follow(&[(340.0, 200.0)], LEVEL, VIEW);
+
+    assert_eq!(camera.x, 320.0);
+}
+
+#[test]
+fn never_shows_past_the_edges_of_the_level() {
+    let mut camera = still();
+
+    camera.follow(&[(0.0, 480.0)], LEVEL, VIEW);
+    assert_eq!((camera.x, camera.y), (128.0, 408.0));
+
+    // A level smaller than the screen just sits in the middle
+    camera.follow(&[(0.0, 0.0)], (128.0, 96.0), VIEW);
+    assert_eq!((camera.x, camera.y), (64.0, 48.0));
+}
+
+#[test]
+fn overview_zooms_out_to_fit_the_whole_level() {
+    let mut camera = still();
+    camera.overview = true;
+
+    camera.follow(&[(0.0, 0.0)], LEVEL, VIEW);
+
+    assert_eq!(camera.zoom, 0.3);
+    assert_eq!((camera.x, camera.y), (320.0, 240.0));
+}
+
+#[test]
+fn zooms_out_to_keep_every_cleaner_in_frame() {
+    let mut camera = still();
+
+    camera.follow(&[(100.0, 200.0), (400.0, 200.0)], LEVEL, VIEW);
+
+    // 300 pixels apart plus the margin on both sides
+    assert_eq!(camera.zoom, VIEW.0 / (300.0 + 2.0 * camera.margin));
+    assert_eq!((camera.x, camera.y), (250.0, 200.0));
+}
+
+#[test]
+fn never_zooms_out_past_the_minimum() {
+    let mut camera = still();
+
+    camera.follow(&[(0.0, 0.0), (640.0, 480.0)], LEVEL, VIEW);
+
+    assert_eq!(camera.zoom, camera.min_zoom);
+}
@@ -0,0 +1,64 @@
+use the_cleaners::level::{vec2, Direction, Par, Spawn};
+use the_cleaners::levels::{self, FormatError, LevelDefinition, FORMAT_VERSION};
+use the_cleaners::sim;
+
+fn level() -> LevelDefinition {
+    let state = sim::parse(
+        "
+        #####
+        #@.B#
+        #..K#
+        #####
+        ",
+    );
+
+    LevelDefinition {
+        name: "Test".to_string(),
+        par: Par {
+            three_stars: 10,
+            two_stars: 20,
+        },
+        columns: state.grid.0,
+        spawn: Spawn {
+            position: vec2(1, 1),
+            facing: Direction::Left,
+            character: None,
+        },
+    }
+}
+
+#[test]
+fn levels_survive_a_round_trip() {
+    let levels = vec![level(), level()];
+
+    assert_eq!(levels::decode(&levels::encode(&levels)).unwrap(), levels);
+}
+
+#[test]
+fn files_from_another_format_version_are_rejected() {
+    let mut bytes = levels::encode(&[level()]);
+    // The version comes right after the four byte magic
+    bytes[4..6].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+
+    assert!(matches!(
+        levels::decode(&bytes),
+        Err(FormatError::Version { found }) if found == FORMAT_VERSION + 1
+    ));
+    assert!(matches!(
+        levels::decode(b"not levels"),
+        Err(FormatError::NotALevelFile)
+    ));
+}
+
+#[test]
+fn shipped_levels_decode() {
+    let levels = levels::all();
+
+    assert_eq!(
+        levels
+            .iter()
+            .map(|level| (level.name.as_str(), level.spawn.position))
+            .collect::<Vec<_>>(),
+        [("Level_0", vec2(7, 13)), ("Level_1", vec2(18, 23))]
+    );
+}
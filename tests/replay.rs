@@ -0,0 +1,150 @@
+use the_cleaners::level::vec2;
+use the_cleaners::replay::{FrameInput, InputLog, Pad, PLAYERS};
+use the_cleaners::{play_back, sim};
+use turbo::borsh::BorshSerialize;
+use turbo::prelude::{Button, Gamepad};
+
+fn gamepad(buttons: [Button; 10]) -> Gamepad<Button> {
+    let [up, down, left, right, a, b, x, y, start, select] = buttons;
+
+    Gamepad {
+        up,
+        down,
+        left,
+        right,
+        a,
+        b,
+        x,
+        y,
+        start,
+        select,
+    }
+}
+
+fn frame(tick: usize, pad: Pad) -> FrameInput {
+    FrameInput {
+        tick,
+        pads: [pad; PLAYERS],
+    }
+}
+
+fn pressing_a() -> Pad {
+    let mut buttons = [Button::Released; 10];
+    buttons[4] = Button::Pressed;
+
+    gamepad(buttons).into()
+}
+
+#[test]
+fn pads_keep_every_button_state() {
+    let states = [
+        Button::Released,
+        Button::JustPressed,
+        Button::Pressed,
+        Button::JustReleased,
+    ];
+
+    // Every button goes through every state, each one out of step with its neighbours
+    for offset in 0..states.len() {
+        let buttons = std::array::from_fn(|index| states[(index + offset) % states.len()]);
+        let pad: Pad = gamepad(buttons).into();
+
+        assert_eq!(Gamepad::<Button>::from(pad), gamepad(buttons));
+    }
+}
+
+#[test]
+fn pads_tell_buttons_apart() {
+    let pads = (0..10)
+        .map(|index| {
+            let mut buttons = [Button::Released; 10];
+            buttons[index] = Button::Pressed;
+            gamepad(buttons).into()
+        })
+        .collect::<Vec<Pad>>();
+
+    pads.iter().enumerate().for_each(|(index, pad)| {
+        assert!(!pads[index + 1..].contains(pad));
+        assert_ne!(*pad, Pad::default());
+    });
+}
+
+#[test]
+fn unchanged_consecutive_frames_are_merged() {
+    let mut once = InputLog::default();
+    once.record(frame(10, pressing_a()));
+
+    let mut held = InputLog::default();
+    (10..110).for_each(|tick| held.record(frame(tick, pressing_a())));
+
+    // A hundred frames of holding a button take up no more room than one
+    assert_eq!(
+        held.try_to_vec().unwrap().len(),
+        once.try_to_vec().unwrap().len()
+    );
+    assert_eq!(held.frame(0), Some(frame(10, pressing_a())));
+    assert_eq!(held.frame(99), Some(frame(109, pressing_a())));
+    assert_eq!(held.frame(100), None);
+}
+
+#[test]
+fn frames_come_back_in_order_across_changes_and_gaps() {
+    let frames = [
+        frame(0, Pad::default()),
+        frame(1, Pad::default()),
+        frame(2, pressing_a()),
+        // Ticks skipped while the level wasn't being played
+        frame(7, pressing_a()),
+        frame(8, Pad::default()),
+    ];
+
+    let mut log = InputLog::default();
+    frames.iter().for_each(|&input| log.record(input));
+
+    frames.iter().enumerate().for_each(|(index, &input)| {
+        assert_eq!(log.frame(index), Some(input));
+    });
+    assert_eq!(log.frame(frames.len()), None);
+    assert_eq!(InputLog::default().frame(0), None);
+}
+
+#[test]
+fn holding_two_directions_plays_back_the_same_every_time() {
+    let holding = |button| {
+        let mut buttons = [Button::Released; 10];
+        // Up and right
+        buttons[0] = button;
+        buttons[3] = button;
+
+        gamepad(buttons).into()
+    };
+
+    // Both directions go down on the same frame, so neither is newer than the other
+    let mut log = InputLog::default();
+    let mut tick = 0;
+    for _ in 0..4 {
+        let presses = std::iter::once(holding(Button::JustPressed))
+            .chain(std::iter::repeat_n(holding(Button::Pressed), 40))
+            .chain(std::iter::repeat_n(Pad::default(), 20));
+
+        for pad in presses {
+            log.record(frame(tick, pad));
+            tick += 1;
+        }
+    }
+
+    let level = sim::parse(
+        "
+        ##########
+        #........#
+        #........#
+        #........#
+        #@.......#
+        ##########
+        ",
+    );
+    let played = play_back(level.clone(), &log);
+
+    assert_ne!(played.cleaners[0].position, vec2(1, 4));
+    (0..20).for_each(|_| assert_eq!(play_back(level.clone(), &log), played));
+}
@@ -0,0 +1,731 @@
+use the_cleaners::level::{
+    vec2, Action, BloodLevel, BodyLevel, Character, Direction, Item, LevelFinished, Objectives,
+    Par, BLEACH, BODY_CHOPPING_TIME, CLEANING_TIME, POOLING_TIME, SPONGE, TICKS_PER_SECOND,
+};
+use the_cleaners::sim::{hold, parse, run};
+
+#[test]
+fn blood_level_increments_up_to_venti() {
+    let mut level = BloodLevel::None;
+
+    assert!(level.increment());
+    assert_eq!(level, BloodLevel::Tall(CLEANING_TIME));
+    assert!(level.increment());
+    assert_eq!(level, BloodLevel::Grande(CLEANING_TIME));
+    assert!(level.increment());
+    assert_eq!(level, BloodLevel::Venti(CLEANING_TIME));
+    assert!(!level.increment());
+    assert_eq!(level, BloodLevel::Venti(CLEANING_TIME));
+}
+
+#[test]
+fn blood_level_decrements_down_to_none() {
+    let mut level = BloodLevel::Venti(3);
+
+    assert!(level.decrement());
+    assert_eq!(level, BloodLevel::Grande(CLEANING_TIME));
+    assert!(level.decrement());
+    assert_eq!(level, BloodLevel::Tall(CLEANING_TIME));
+    assert!(level.decrement());
+    assert_eq!(level, BloodLevel::None);
+    assert!(!level.decrement());
+}
+
+#[test]
+fn body_level_goes_through_every_stage_once() {
+    let mut level = BodyLevel::Start;
+    let mut stages = 1;
+
+    while let Some(lower) = level.lower() {
+        level = lower;
+        stages += 1;
+    }
+
+    assert_eq!(level, BodyLevel::Seven);
+    assert_eq!(stages, 8);
+}
+
+#[test]
+fn walls_and_furniture_block_movement_but_turn_the_player() {
+    let mut state = parse(
+        "
+        #####
+        #@T.#
+        #####
+        ",
+    );
+
+    run(&mut state, [Action::Move(Direction::Up)]);
+    assert_eq!(state.cleaners[0].position, vec2(1, 1));
+    assert_eq!(state.cleaners[0].facing, Direction::Up);
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(state.cleaners[0].position, vec2(1, 1));
+    assert_eq!(state.cleaners[0].facing, Direction::Right);
+}
+
+#[test]
+fn moving_updates_the_player_flag_on_tiles() {
+    let mut state = parse(
+        "
+        ####
+        #@.#
+        ####
+        ",
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+
+    assert_eq!(state.cleaners[0].position, vec2(2, 1));
+    assert!(!state.grid[vec2(1, 1)].player);
+    assert!(state.grid[vec2(2, 1)].player);
+}
+
+#[test]
+fn moving_remembers_where_the_step_started_for_drawing() {
+    let mut state = parse(
+        "
+        #####
+        #@..#
+        #####
+        ",
+    );
+    state.elapsed = 7;
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(state.cleaners[0].previous_position, vec2(1, 1));
+    assert_eq!(state.cleaners[0].position, vec2(2, 1));
+    assert_eq!(state.cleaners[0].moved_at, 7);
+
+    // Bumping into a wall turns on the spot without replaying the step
+    state.elapsed = 9;
+    run(&mut state, [Action::Move(Direction::Up)]);
+    assert_eq!(state.cleaners[0].previous_position, vec2(2, 1));
+    assert_eq!(state.cleaners[0].moved_at, 7);
+}
+
+#[test]
+fn stepping_on_a_body_soaks_boots_and_tracks_blood() {
+    let mut state = parse(
+        "
+        ######
+        #@B..#
+        ######
+        ",
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.grid[vec2(2, 1)].blood_level,
+        BloodLevel::Venti(CLEANING_TIME)
+    );
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Venti(CLEANING_TIME)
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.grid[vec2(3, 1)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Grande(CLEANING_TIME)
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.grid[vec2(4, 1)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+}
+
+#[test]
+fn items_can_be_picked_up_and_dropped_on_empty_tiles() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #K#
+        ###
+        ",
+    );
+
+    run(&mut state, [Action::Interact]);
+    assert_eq!(state.cleaners[0].inventory, Item::Knife);
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::None);
+
+    run(&mut state, [Action::Drop]);
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::Knife);
+}
+
+#[test]
+fn cannot_pick_up_with_full_hands_or_drop_onto_an_item() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #K#
+        ###
+        ",
+    );
+    state.cleaners[0].inventory = Item::Sponge(0);
+
+    run(&mut state, [Action::Interact, Action::Drop]);
+
+    assert_eq!(state.cleaners[0].inventory, Item::Sponge(0));
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::Knife);
+}
+
+#[test]
+fn bag_roll_hands_out_bags_without_running_out() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #R#
+        ###
+        ",
+    );
+
+    run(&mut state, [Action::Interact]);
+
+    assert_eq!(state.cleaners[0].inventory, Item::Bag);
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::BagRoll);
+}
+
+#[test]
+fn chopping_a_body_needs_a_knife() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #B#
+        ###
+        ",
+    );
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+    assert_eq!(
+        state.grid[vec2(1, 2)].item,
+        Item::Body(BodyLevel::Start, BODY_CHOPPING_TIME)
+    );
+    assert_eq!(state.cleaners[0].worked_at, None);
+
+    state.cleaners[0].inventory = Item::Knife;
+    state.elapsed = 3;
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize - 1),
+    );
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::Body(BodyLevel::Start, 1));
+    assert_eq!(state.cleaners[0].worked_at, Some(3));
+
+    run(&mut state, [Action::Interact]);
+    assert_eq!(
+        state.grid[vec2(1, 2)].item,
+        Item::Body(BodyLevel::One, BODY_CHOPPING_TIME)
+    );
+}
+
+#[test]
+fn bagging_a_chopped_body_leaves_a_body_bag_nearby() {
+    let mut state = parse(
+        "
+        #####
+        #@.B#
+        #...#
+        #####
+        ",
+    );
+    state.grid[vec2(3, 1)].item = Item::Body(BodyLevel::Four, BODY_CHOPPING_TIME);
+    state.cleaners[0].inventory = Item::Bag;
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+    assert_eq!(
+        state.grid[vec2(3, 1)].item,
+        Item::Body(BodyLevel::Five, BODY_CHOPPING_TIME)
+    );
+    assert_eq!(state.grid[vec2(3, 2)].item, Item::BodyBag);
+}
+
+#[test]
+fn cleaning_the_last_blood_finishes_the_level() {
+    let mut state = parse(
+        "
+        #######
+        #@..2.#
+        #S#####
+        #######
+        ",
+    );
+
+    run(
+        &mut state,
+        [
+            Action::Interact,
+            Action::Move(Direction::Right),
+            Action::Move(Direction::Right),
+        ],
+    );
+    assert_eq!(state.cleaners[0].inventory, Item::Sponge(0));
+
+    let sponge_time = (CLEANING_TIME / SPONGE.strength) as usize;
+    let finished = run(&mut state, hold(Action::Interact, sponge_time));
+    assert_eq!(finished, LevelFinished::No);
+    assert_eq!(
+        state.grid[vec2(4, 1)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert!(!state.is_finished());
+
+    let finished = run(&mut state, hold(Action::Interact, sponge_time));
+    assert_eq!(finished, LevelFinished::Yes);
+    assert_eq!(state.grid[vec2(4, 1)].blood_level, BloodLevel::None);
+    assert_eq!(state.stats.blood_tiles_cleaned, 1);
+}
+
+#[test]
+fn cleaning_needs_a_cleaning_item() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #1#
+        ###
+        ",
+    );
+    state.cleaners[0].inventory = Item::Knife;
+
+    run(&mut state, hold(Action::Interact, CLEANING_TIME as usize));
+
+    assert_eq!(
+        state.grid[vec2(1, 2)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+}
+
+#[test]
+fn delivering_the_last_body_bag_finishes_the_level() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #D#
+        ###
+        ",
+    );
+    state.cleaners[0].inventory = Item::BodyBag;
+    state.recount_objectives();
+
+    assert_eq!(run(&mut state, [Action::Drop]), LevelFinished::Yes);
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+    assert_eq!(state.stats.body_bags_delivered, 1);
+}
+
+#[test]
+fn body_bags_left_on_the_floor_keep_the_level_going() {
+    let mut state = parse(
+        "
+        ####
+        #@O#
+        #D.#
+        ####
+        ",
+    );
+    state.cleaners[0].inventory = Item::BodyBag;
+    state.recount_objectives();
+
+    assert_eq!(run(&mut state, [Action::Drop]), LevelFinished::No);
+    assert!(!state.is_finished());
+}
+
+#[test]
+fn body_bags_are_never_placed_inside_walls() {
+    let mut state = parse(
+        "
+        #####
+        #@B.#
+        #####
+        ",
+    );
+    state.grid[vec2(2, 1)].item = Item::Body(BodyLevel::Four, BODY_CHOPPING_TIME);
+    state.cleaners[0].inventory = Item::Bag;
+    state.cleaners[0].facing = Direction::Right;
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+
+    assert_eq!(state.grid[vec2(2, 2)].item, Item::None);
+    assert_eq!(state.grid[vec2(3, 1)].item, Item::BodyBag);
+}
+
+#[test]
+fn body_bags_are_never_placed_out_of_reach() {
+    let mut state = parse(
+        "
+        ######
+        #@B#.#
+        ######
+        ",
+    );
+    state.grid[vec2(2, 1)].item = Item::Body(BodyLevel::Four, BODY_CHOPPING_TIME);
+    state.cleaners[0].inventory = Item::Bag;
+    state.cleaners[0].facing = Direction::Right;
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+
+    assert_eq!(state.grid[vec2(4, 1)].item, Item::None);
+    assert_eq!(state.cleaners[0].inventory, Item::BodyBag);
+}
+
+#[test]
+fn body_bags_end_up_in_hands_when_there_is_no_room() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #B#
+        ###
+        ",
+    );
+    state.grid[vec2(1, 2)].item = Item::Body(BodyLevel::Four, BODY_CHOPPING_TIME);
+    state.cleaners[0].inventory = Item::Bag;
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+
+    assert_eq!(state.cleaners[0].inventory, Item::BodyBag);
+    assert!(state
+        .grid
+        .iter()
+        .flatten()
+        .all(|tile| tile.item != Item::BodyBag));
+}
+
+#[test]
+fn levels_without_a_wall_border_stop_the_player_at_the_edge() {
+    let mut state = parse(
+        "
+        @.
+        .K
+        ",
+    );
+
+    run(
+        &mut state,
+        [
+            Action::Move(Direction::Up),
+            Action::Interact,
+            Action::Move(Direction::Left),
+            Action::Drop,
+        ],
+    );
+
+    assert_eq!(state.cleaners[0].position, vec2(0, 0));
+    assert_eq!(state.cleaners[0].facing, Direction::Left);
+    assert_eq!(state.in_front_of(0), None);
+    assert!(state.grid.get(vec2(2, 0)).is_none());
+}
+
+#[test]
+fn par_times_map_to_star_ratings() {
+    let par = Par {
+        three_stars: 30,
+        two_stars: 60,
+    };
+
+    assert_eq!(par.stars(30 * TICKS_PER_SECOND), 3);
+    assert_eq!(par.stars(30 * TICKS_PER_SECOND + 1), 2);
+    assert_eq!(par.stars(60 * TICKS_PER_SECOND), 2);
+    assert_eq!(par.stars(60 * TICKS_PER_SECOND + 1), 1);
+}
+
+#[test]
+fn cleaners_bump_into_each_other_and_hand_items_over() {
+    let mut state = parse(
+        "
+        #####
+        #@..#
+        #####
+        ",
+    );
+
+    assert!(state.join(Character::TwoToes));
+    assert_eq!(state.cleaners[1].position, vec2(2, 1));
+    assert!(state.grid[vec2(2, 1)].player);
+
+    state.cleaners[0].inventory = Item::Sponge(0);
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(state.cleaners[0].position, vec2(1, 1));
+
+    run(&mut state, [Action::Drop]);
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+    assert_eq!(state.cleaners[1].inventory, Item::Sponge(0));
+    assert_eq!(state.grid[vec2(2, 1)].item, Item::None);
+}
+
+#[test]
+fn police_only_arrive_when_a_countdown_was_set() {
+    let mut state = parse("#@#");
+
+    state.elapsed = usize::MAX;
+    assert!(!state.police_arrived());
+
+    state.police_arrive_at = Some(60 * TICKS_PER_SECOND);
+    state.elapsed = 60 * TICKS_PER_SECOND - 1;
+    assert!(!state.police_arrived());
+    state.elapsed += 1;
+    assert!(state.police_arrived());
+}
+
+#[test]
+fn clean_boots_pick_up_blood_and_leave_footprints() {
+    let mut state = parse(
+        "
+        #####
+        #@1.#
+        #####
+        ",
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(state.grid[vec2(2, 1)].footprints, None);
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(state.cleaners[0].blood_on_boots, BloodLevel::None);
+    assert_eq!(
+        state.grid[vec2(3, 1)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(state.grid[vec2(3, 1)].footprints, Some(Direction::Right));
+
+    state.cleaners[0].inventory = Item::Sponge(0);
+    run(&mut state, [Action::Move(Direction::Left)]);
+    state.cleaners[0].facing = Direction::Right;
+    run(&mut state, hold(Action::Interact, CLEANING_TIME as usize));
+    assert_eq!(state.grid[vec2(3, 1)].blood_level, BloodLevel::None);
+    assert_eq!(state.grid[vec2(3, 1)].footprints, None);
+}
+
+#[test]
+fn venti_blood_pools_into_clean_floor_over_time() {
+    let mut state = parse(
+        "
+        #####
+        #@3.#
+        #.#.#
+        #####
+        ",
+    );
+
+    state.elapsed = POOLING_TIME;
+    state.spread_blood();
+    assert_eq!(state.grid[vec2(3, 1)].blood_level, BloodLevel::None);
+
+    state.pooling = true;
+    state.elapsed = POOLING_TIME - 1;
+    state.spread_blood();
+    assert_eq!(state.grid[vec2(3, 1)].blood_level, BloodLevel::None);
+
+    // The wall above comes first and is skipped
+    state.elapsed = POOLING_TIME;
+    state.spread_blood();
+    assert_eq!(
+        state.grid[vec2(3, 1)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+}
+
+#[test]
+fn sponges_saturate_until_rinsed_at_a_sink() {
+    let mut state = parse(
+        "
+        ####
+        #@W#
+        #3##
+        ####
+        ",
+    );
+    state.cleaners[0].inventory = Item::Sponge(SPONGE.uses - 1);
+
+    run(&mut state, hold(Action::Interact, CLEANING_TIME as usize));
+    assert_eq!(
+        state.grid[vec2(1, 2)].blood_level,
+        BloodLevel::Grande(CLEANING_TIME)
+    );
+    assert_eq!(state.cleaners[0].inventory, Item::Sponge(SPONGE.uses));
+    assert!(!state.cleaners[0].inventory.can_clean());
+
+    run(&mut state, hold(Action::Interact, CLEANING_TIME as usize));
+    assert_eq!(
+        state.grid[vec2(1, 2)].blood_level,
+        BloodLevel::Grande(CLEANING_TIME)
+    );
+
+    state.cleaners[0].facing = Direction::Right;
+    run(&mut state, [Action::Interact]);
+    assert_eq!(state.cleaners[0].inventory, Item::Sponge(0));
+}
+
+#[test]
+fn bleach_takes_off_a_level_at_once_until_it_runs_out() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #3#
+        ###
+        ",
+    );
+    state.cleaners[0].inventory = Item::Bleach(BLEACH.uses - 2);
+
+    run(&mut state, [Action::Interact]);
+    assert_eq!(
+        state.grid[vec2(1, 2)].blood_level,
+        BloodLevel::Grande(CLEANING_TIME)
+    );
+
+    run(&mut state, [Action::Interact]);
+    assert_eq!(
+        state.grid[vec2(1, 2)].blood_level,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+}
+
+#[test]
+fn doormats_wipe_boots_without_leaving_blood() {
+    let mut state = parse(
+        "
+        ######
+        #@BMM#
+        ######
+        ",
+    );
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Grande(CLEANING_TIME)
+    );
+    assert_eq!(state.grid[vec2(3, 1)].blood_level, BloodLevel::None);
+    assert_eq!(state.grid[vec2(3, 1)].footprints, None);
+
+    run(&mut state, [Action::Move(Direction::Right)]);
+    assert_eq!(
+        state.cleaners[0].blood_on_boots,
+        BloodLevel::Tall(CLEANING_TIME)
+    );
+    assert_eq!(state.grid[vec2(4, 1)].blood_level, BloodLevel::None);
+}
+
+#[test]
+fn objectives_count_what_is_left_including_held_body_bags() {
+    let mut state = parse(
+        "
+        #######
+        #@B1O2#
+        #######
+        ",
+    );
+
+    assert_eq!(
+        state.objectives(),
+        Objectives {
+            blood_tiles: 2,
+            bodies: 1,
+            body_bags: 1,
+        }
+    );
+
+    state.cleaners[0].inventory = Item::BodyBag;
+    state.recount_objectives();
+    assert_eq!(state.objectives().body_bags, 2);
+}
+
+#[test]
+fn rewinding_takes_back_every_change_recorded_since_the_snapshot() {
+    let mut state = parse(
+        "
+        ######
+        #@K.B#
+        #2...#
+        ######
+        ",
+    );
+    let start = state.clone();
+    let mut snapshot = state.snapshot();
+
+    let actions = [
+        Action::Move(Direction::Right),
+        Action::Interact,
+        Action::Move(Direction::Down),
+        Action::Move(Direction::Right),
+        Action::Move(Direction::Up),
+        Action::Move(Direction::Right),
+    ]
+    .into_iter()
+    .chain(hold(Action::Interact, BODY_CHOPPING_TIME as usize));
+    for action in actions {
+        state.apply(0, action);
+        snapshot.record(state.changed_tiles());
+    }
+
+    assert_ne!(state.grid, start.grid);
+    state.rewind(snapshot);
+
+    assert_eq!(state.grid, start.grid);
+    assert_eq!(state.cleaners, start.cleaners);
+    assert_eq!(state.objectives(), start.objectives());
+}
+
+#[test]
+fn the_last_body_bag_goes_where_the_body_was() {
+    let mut state = parse(
+        "
+        ###
+        #@#
+        #B#
+        ###
+        ",
+    );
+    state.grid[vec2(1, 2)].item = Item::Body(BodyLevel::Seven, BODY_CHOPPING_TIME);
+    state.cleaners[0].inventory = Item::Bag;
+
+    run(
+        &mut state,
+        hold(Action::Interact, BODY_CHOPPING_TIME as usize),
+    );
+
+    assert_eq!(state.grid[vec2(1, 2)].item, Item::BodyBag);
+    assert_eq!(state.cleaners[0].inventory, Item::None);
+}